@@ -2,7 +2,7 @@ use feo_types::{
     span::{Span, Spanned},
     type_utils::{
         Bracket, HashBang, HashSign, KwAbstract, KwContract, KwExport, KwExtern, KwPayable,
-        KwStorage, KwTest, KwTopic, KwUnsafe,
+        KwStorage, KwTest, KwTopic, KwUnsafe, Parenthesis,
     },
     Identifier,
 };
@@ -20,6 +20,27 @@ pub enum AttributeKind {
     KwUnsafe(KwUnsafe),
 
     Identifier(Identifier),
+
+    Lint(LintAttr),
+}
+
+/// Attribute that sets the level of lints in the item that it is on,
+/// e.g., `#[allow(unnecessary_raw_identifier)]` (the level is one of `allow`, `warn`, `deny` or `forbid`)
+#[derive(Debug, Clone)]
+pub struct LintAttr {
+    pub level: Identifier,
+    pub open_parenthesis: Parenthesis,
+    pub lints: Vec<Identifier>,
+    pub close_parenthesis: Parenthesis,
+}
+
+impl Spanned for LintAttr {
+    fn span(&self) -> Span {
+        let s1 = self.level.span();
+        let s2 = self.close_parenthesis.span();
+
+        Span::join(s1, s2)
+    }
 }
 
 #[derive(Debug, Clone)]
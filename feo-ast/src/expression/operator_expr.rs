@@ -1,10 +1,11 @@
 use feo_types::{
     span::{Span, Spanned},
     type_utils::{
-        Ampersand, Asterisk, AsteriskEquals, Bang, BangEquals, Caret, DblAmpersand, DblEquals,
-        DblGreaterThan, DblLessThan, DblPipe, Equals, ForwardSlash, ForwardSlashEquals,
-        GreaterThan, GreaterThanEquals, KwAs, KwMut, LessThan, LessThanEquals, Minus, MinusEquals,
-        Percent, PercentEquals, Pipe, Plus, PlusEquals, QuestionMark,
+        Ampersand, AmpersandEquals, Asterisk, AsteriskEquals, Bang, BangEquals, Caret, CaretEquals,
+        DblAmpersand, DblEquals, DblGreaterThan, DblGreaterThanEquals, DblLessThan,
+        DblLessThanEquals, DblPipe, Equals, ForwardSlash, ForwardSlashEquals, GreaterThan,
+        GreaterThanEquals, KwAs, KwMut, LessThan, LessThanEquals, Minus, MinusEquals, Percent,
+        PercentEquals, Pipe, PipeEquals, Plus, PlusEquals, QuestionMark,
     },
 };
 
@@ -74,6 +75,11 @@ pub enum CompoundAssignOperatorKind {
     MultiplyAssign(AsteriskEquals),
     DivideAssign(ForwardSlashEquals),
     ModulusAssign(PercentEquals),
+    BitwiseAndAssign(AmpersandEquals),
+    BitwiseOrAssign(PipeEquals),
+    BitwiseXorAssign(CaretEquals),
+    ShiftLeftAssign(DblLessThanEquals),
+    ShiftRightAssign(DblGreaterThanEquals),
 }
 
 #[derive(Debug, Clone)]
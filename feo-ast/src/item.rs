@@ -9,9 +9,12 @@ mod trait_def;
 mod type_def;
 mod visibility;
 
-use feo_types::span::{Span, Spanned};
+use feo_types::{
+    span::{Span, Spanned},
+    Identifier,
+};
 
-use crate::expression::TermCollection;
+use crate::{attribute::OuterAttr, expression::TermCollection};
 
 pub use self::{
     const_var_def::{ConstVarDef, StaticVarDef},
@@ -45,6 +48,72 @@ pub enum Item {
     TypeDef(TypeDef),
 }
 
+impl Item {
+    /// Return the outer attributes of the item (e.g., `#[allow(unnecessary_raw_identifier)]`).
+    pub fn outer_attributes(&self) -> &[OuterAttr] {
+        let attributes_opt = match self {
+            Item::ConstVarDef(cv) => &cv.attributes_opt,
+            Item::StaticVarDef(sv) => &sv.attributes_opt,
+            Item::EnumDef(ed) => &ed.attributes_opt,
+            Item::FuncSig(fs) => &fs.attributes_opt,
+            Item::FuncWithBlock(fwb) => &fwb.function_sig.attributes_opt,
+            Item::InherentImplBlock(ii) => &ii.outer_attributes_opt,
+            Item::TraitImplBlock(ti) => &ti.outer_attributes_opt,
+            Item::ImportDecl(imp) => &imp.attributes_opt,
+            Item::ModuleWithBlock(mwb) => &mwb.attributes_opt,
+            Item::ModuleWithoutBlock(m) => &m.attributes_opt,
+            Item::StructDef(sd) => &sd.attributes_opt,
+            Item::TupleStructDef(tsd) => &tsd.attributes_opt,
+            Item::TraitDef(td) => &td.outer_attributes_opt,
+            Item::TypeDef(tad) => &tad.attributes_opt,
+        };
+
+        attributes_opt.as_deref().unwrap_or(&[])
+    }
+
+    /// Return the name that the item declares, or `None` if it does not declare one
+    /// (e.g., an `impl` block or an import).
+    pub fn name(&self) -> Option<&Identifier> {
+        match self {
+            Item::ConstVarDef(cv) => Some(&cv.item_name),
+            Item::StaticVarDef(sv) => Some(&sv.item_name),
+            Item::EnumDef(ed) => Some(&ed.enum_name),
+            Item::FuncSig(fs) => Some(&fs.func_name),
+            Item::FuncWithBlock(fwb) => Some(&fwb.function_sig.func_name),
+            Item::InherentImplBlock(_) | Item::TraitImplBlock(_) | Item::ImportDecl(_) => None,
+            Item::ModuleWithBlock(mwb) => Some(&mwb.module_name),
+            Item::ModuleWithoutBlock(m) => Some(&m.module_name),
+            Item::StructDef(sd) => Some(&sd.struct_name),
+            Item::TupleStructDef(tsd) => Some(&tsd.struct_name),
+            Item::TraitDef(td) => Some(&td.trait_name),
+            Item::TypeDef(tad) => Some(&tad.type_name),
+        }
+    }
+
+    /// Replace the outer attributes of the item
+    /// (e.g., with attributes that were parsed before the kind of item was known).
+    pub fn set_outer_attributes(&mut self, attributes_opt: Option<Vec<OuterAttr>>) {
+        let item_attributes_opt = match self {
+            Item::ConstVarDef(cv) => &mut cv.attributes_opt,
+            Item::StaticVarDef(sv) => &mut sv.attributes_opt,
+            Item::EnumDef(ed) => &mut ed.attributes_opt,
+            Item::FuncSig(fs) => &mut fs.attributes_opt,
+            Item::FuncWithBlock(fwb) => &mut fwb.function_sig.attributes_opt,
+            Item::InherentImplBlock(ii) => &mut ii.outer_attributes_opt,
+            Item::TraitImplBlock(ti) => &mut ti.outer_attributes_opt,
+            Item::ImportDecl(imp) => &mut imp.attributes_opt,
+            Item::ModuleWithBlock(mwb) => &mut mwb.attributes_opt,
+            Item::ModuleWithoutBlock(m) => &mut m.attributes_opt,
+            Item::StructDef(sd) => &mut sd.attributes_opt,
+            Item::TupleStructDef(tsd) => &mut tsd.attributes_opt,
+            Item::TraitDef(td) => &mut td.outer_attributes_opt,
+            Item::TypeDef(tad) => &mut tad.attributes_opt,
+        };
+
+        *item_attributes_opt = attributes_opt;
+    }
+}
+
 impl Spanned for Item {
     fn span(&self) -> Span {
         match self {
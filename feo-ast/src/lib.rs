@@ -3,6 +3,7 @@ pub mod expression;
 pub mod item;
 pub mod path;
 pub mod pattern;
+pub mod source_unit;
 pub mod statement;
 pub mod token;
 pub mod tokenize;
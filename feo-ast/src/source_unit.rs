@@ -0,0 +1,56 @@
+use feo_types::{
+    span::{Span, Spanned},
+    type_utils::{KwContract, KwLibrary, KwScript, Semicolon},
+};
+
+use crate::{attribute::InnerAttr, item::Item};
+
+/// Kind of program that a source file compiles to.
+#[derive(Debug, Clone)]
+pub enum ProgramKind {
+    KwContract(KwContract),
+    KwLibrary(KwLibrary),
+    KwScript(KwScript),
+}
+
+impl Spanned for ProgramKind {
+    fn span(&self) -> Span {
+        match self {
+            ProgramKind::KwContract(kc) => kc.span(),
+            ProgramKind::KwLibrary(kl) => kl.span(),
+            ProgramKind::KwScript(ks) => ks.span(),
+        }
+    }
+}
+
+/// Declaration of the kind of program at the top of a source file (e.g., `contract;`).
+#[derive(Debug, Clone)]
+pub struct ProgramHeader {
+    pub program_kind: ProgramKind,
+    pub semicolon: Semicolon,
+}
+
+impl Spanned for ProgramHeader {
+    fn span(&self) -> Span {
+        let s1 = self.program_kind.span();
+        let s2 = self.semicolon.span();
+
+        Span::join(s1, s2)
+    }
+}
+
+/// Root node of the syntax tree of a source file, i.e., its inner attributes (e.g., `#![contract]`),
+/// program header and items, in that order. Its span covers the whole file.
+#[derive(Debug, Clone)]
+pub struct SourceUnit {
+    pub inner_attributes_opt: Option<Vec<InnerAttr>>,
+    pub program_header_opt: Option<ProgramHeader>,
+    pub items: Vec<Item>,
+    pub span: Span,
+}
+
+impl Spanned for SourceUnit {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
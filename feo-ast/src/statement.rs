@@ -29,11 +29,9 @@ impl Spanned for ExprStatement {
             self.expression.span().end()
         };
 
-        let source = self.expression.span().source();
+        let src_id = self.expression.span().source_id();
 
-        let span = Span::new(source.as_str(), start_pos, end_pos);
-
-        span
+        Span::new(src_id, start_pos, end_pos)
     }
 }
 
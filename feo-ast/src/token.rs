@@ -1,7 +1,12 @@
-use std::fmt;
+use std::{fmt, slice, vec};
 
 use feo_types::{
-    literal::{FloatType, IntType, Literal, UIntType},
+    comment::CommentKind,
+    delimiter::DelimOrientation,
+    doc_comment::DocCommentKind,
+    literal::{FloatType, IntType, Literal, LiteralType, UIntType},
+    punctuation::{self, PuncKind},
+    source_map::{SourceId, SourceMap},
     span::{Span, Spanned},
     Comment, Delimiter, DocComment, Identifier, Keyword, Punctuation, U256,
 };
@@ -10,6 +15,8 @@ use feo_types::{
 pub enum Token {
     CharLit(Literal<char>),
     StringLit(Literal<String>),
+    ByteLit(Literal<u8>),
+    ByteStringLit(Literal<Vec<u8>>),
     BoolLit(Literal<bool>),
     IntLit(Literal<IntType>),
     UIntLit(Literal<UIntType>),
@@ -25,14 +32,236 @@ pub enum Token {
     Delim(Delimiter),
     Punc(Punctuation),
 
+    /// Placeholder for input that could not be lexed (the error is logged to the `Handler`),
+    /// with the span and text of that input
+    Error(Span, String),
+
     EOF,
 }
 
+impl Token {
+    /// Return a copy of the token with its span moved by `delta` bytes and into the file
+    /// `src_id` (e.g., to reuse the token in a new version of its file).
+    pub fn shift(&self, src_id: SourceId, delta: isize) -> Token {
+        match self {
+            Token::CharLit(c) => Token::CharLit(shift_literal(c, src_id, delta)),
+            Token::StringLit(s) => Token::StringLit(shift_literal(s, src_id, delta)),
+            Token::ByteLit(b) => Token::ByteLit(shift_literal(b, src_id, delta)),
+            Token::ByteStringLit(bs) => Token::ByteStringLit(shift_literal(bs, src_id, delta)),
+            Token::BoolLit(b) => Token::BoolLit(shift_literal(b, src_id, delta)),
+            Token::IntLit(i) => Token::IntLit(shift_literal(i, src_id, delta)),
+            Token::UIntLit(ui) => Token::UIntLit(shift_literal(ui, src_id, delta)),
+            Token::U256Lit(u) => Token::U256Lit(shift_literal(u, src_id, delta)),
+            Token::FloatLit(f) => Token::FloatLit(shift_literal(f, src_id, delta)),
+            Token::Identifier(id) => {
+                let span = id.span().shift(src_id, delta);
+
+                if id.is_raw {
+                    Token::Identifier(Identifier::new_raw(id.name.clone(), span))
+                } else {
+                    Token::Identifier(Identifier::new(id.name.clone(), span))
+                }
+            }
+            Token::Keyword(k) => Token::Keyword(Keyword::new(
+                k.keyword_kind.clone(),
+                k.span().shift(src_id, delta),
+            )),
+            Token::Comment(c) => Token::Comment(Comment::new(
+                c.comment_kind.clone(),
+                c.data.clone(),
+                c.span().shift(src_id, delta),
+            )),
+            Token::DocComment(dc) => Token::DocComment(DocComment::new(
+                dc.doc_comment_kind.clone(),
+                dc.content.clone(),
+                dc.span().shift(src_id, delta),
+            )),
+            Token::Delim(d) => Token::Delim(Delimiter {
+                span: d.span.shift(src_id, delta),
+                ..d.clone()
+            }),
+            Token::Punc(p) => Token::Punc(Punctuation {
+                span: p.span.shift(src_id, delta),
+                ..p.clone()
+            }),
+            Token::Error(s, text) => Token::Error(s.shift(src_id, delta), text.clone()),
+            Token::EOF => Token::EOF,
+        }
+    }
+
+    /// Print the token as Feo source code, from its value rather than its span
+    /// (so string and char literals are quoted and escaped, and numbers are printed with
+    /// a type suffix unless they lex as the same type without one).
+    pub fn to_source(&self) -> String {
+        match self {
+            Token::CharLit(c) => format!("'{}'", literal_value(c).escape_debug()),
+            Token::StringLit(s) => format!("\"{}\"", literal_value(s).escape_debug()),
+            Token::ByteLit(b) => format!("b'{}'", literal_value(b).escape_ascii()),
+            Token::ByteStringLit(bs) => format!("b\"{}\"", literal_value(bs).escape_ascii()),
+            Token::BoolLit(b) => literal_value(b).to_string(),
+            Token::IntLit(i) => match literal_value(i) {
+                IntType::I32(i) => format!("{}i32", i),
+                IntType::I64(i) if i < 0 => i.to_string(), // ditto negative integers for `i64`
+                IntType::I64(i) => format!("{}i64", i),
+            },
+            Token::UIntLit(ui) => match literal_value(ui) {
+                UIntType::U8(ui) => format!("{}u8", ui),
+                UIntType::U16(ui) => format!("{}u16", ui),
+                UIntType::U32(ui) => format!("{}u32", ui),
+                UIntType::U64(ui) => ui.to_string(), // unsuffixed integers default to `u64`
+            },
+            Token::U256Lit(u) => format!("{}u256", literal_value(u)),
+            // `Debug` prints the shortest representation that parses back to the same value
+            Token::FloatLit(f) => match literal_value(f) {
+                FloatType::F32(f) => format!("{:?}f32", f),
+                FloatType::F64(f) => format!("{:?}f64", f),
+            },
+            Token::Identifier(id) if id.is_raw => format!("r#{}", id.name),
+            Token::Identifier(id) => id.name.clone(),
+            Token::Keyword(k) => k.keyword_kind.as_str().to_string(),
+            Token::Comment(c) => c.data.clone(),
+            Token::DocComment(dc) => match dc.doc_comment_kind {
+                DocCommentKind::OuterDocComment => line_doc_comment("///", &dc.content),
+                DocCommentKind::InnerDocComment => line_doc_comment("//!", &dc.content),
+                DocCommentKind::OuterBlockDocComment => block_doc_comment("/**", &dc.content),
+                DocCommentKind::InnerBlockDocComment => block_doc_comment("/*!", &dc.content),
+            },
+            Token::Delim(d) => d.clone().as_char().to_string(),
+            Token::Punc(p) => p.punc_kind.as_str().to_string(),
+            Token::Error(_, text) => text.clone(),
+            Token::EOF => String::new(),
+        }
+    }
+
+    // whether the token is followed by a newline (line comments run until the end of the line,
+    // and doc comments are printed on their own lines)
+    fn ends_line(&self) -> bool {
+        match self {
+            Token::Comment(c) => matches!(c.comment_kind, CommentKind::LineComment),
+            Token::DocComment(_) => true,
+            _ => false,
+        }
+    }
+}
+
+fn shift_literal<T>(literal: &Literal<T>, src_id: SourceId, delta: isize) -> Literal<T>
+where
+    T: LiteralType + Clone + 'static,
+{
+    Literal::new(literal_value(literal), literal.span().shift(src_id, delta))
+}
+
+fn literal_value<T>(literal: &Literal<T>) -> T
+where
+    T: LiteralType + Clone + 'static,
+{
+    literal.clone().into_inner().expect("literal has no value")
+}
+
+fn line_doc_comment(opener: &str, content: &str) -> String {
+    if content.is_empty() {
+        opener.to_string()
+    } else {
+        format!("{} {}", opener, content)
+    }
+}
+
+// print the content of a block doc comment with a `*` gutter on each line, e.g.:
+// ```
+// /**
+//  * Some documentation
+//  */
+// ```
+fn block_doc_comment(opener: &str, content: &str) -> String {
+    let mut source = opener.to_string();
+
+    for line in content.lines() {
+        source.push_str("\n *");
+
+        if !line.is_empty() {
+            source.push(' ');
+            source.push_str(line);
+        }
+    }
+
+    source.push_str("\n */");
+    source
+}
+
+// whether two adjacent tokens must be separated by whitespace, so that they are not lexed
+// as a single token (or as different tokens) when printed next to each other,
+// e.g., `x` and `y`, `-` and `1`, `/` and `/* comment */`, or `1` and `.` (not `1.`)
+fn needs_space(prev: &Token, prev_source: &str, next_source: &str, is_tuple_index: bool) -> bool {
+    let (Some(a), Some(b)) = (prev_source.chars().last(), next_source.chars().next()) else {
+        return false;
+    };
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    match prev {
+        Token::Punc(_) => {
+            let joined = format!("{}{}", prev_source, next_source);
+
+            punctuation::longest_match(&joined).is_some_and(|(_, len)| len > prev_source.len())
+                || (a == '/' && (b == '/' || b == '*'))
+                || (a == '-' && b.is_ascii_digit())
+        }
+        Token::UIntLit(_) if b == '.' => !is_tuple_index,
+        Token::Error(..) => true,
+        _ => is_word_char(a) && is_word_char(b),
+    }
+}
+
+// whether two adjacent tokens are separated by a space for readability (where it is optional),
+// e.g., not inside parentheses and brackets, before `,` and `;`, or around `.` and `::`
+fn wants_space(prev: &Token, next: &Token) -> bool {
+    let delim_char = |t: &Token| match t {
+        Token::Delim(d) => Some(d.clone().as_char()),
+        _ => None,
+    };
+
+    match (delim_char(prev), delim_char(next)) {
+        (Some('(' | '['), _) | (_, Some(')' | ']')) | (Some('{'), Some('}')) => return false,
+        // function calls and index expressions, e.g., `foo(x)`, `foo(x)(y)` and `array[0]`
+        (Some(')' | ']'), Some('(' | '[')) => return false,
+        (None, Some('(' | '[')) if matches!(prev, Token::Identifier(_)) => return false,
+        _ => (),
+    }
+
+    match (prev, next) {
+        (_, Token::Punc(p))
+            if matches!(
+                p.punc_kind,
+                PuncKind::Colon
+                    | PuncKind::Semicolon
+                    | PuncKind::Comma
+                    | PuncKind::FullStop
+                    | PuncKind::DblColon
+                    | PuncKind::ColonColonAsterisk
+                    | PuncKind::QuestionMark
+            ) =>
+        {
+            false
+        }
+        (Token::Punc(p), _) => !matches!(
+            p.punc_kind,
+            PuncKind::FullStop
+                | PuncKind::DblColon
+                | PuncKind::Bang
+                | PuncKind::HashSign
+                | PuncKind::HashBang
+        ),
+        _ => true,
+    }
+}
+
 impl Spanned for Token {
     fn span(&self) -> Span {
         match self {
             Token::CharLit(c) => c.span(),
             Token::StringLit(s) => s.span(),
+            Token::ByteLit(b) => b.span(),
+            Token::ByteStringLit(bs) => bs.span(),
             Token::BoolLit(b) => b.span(),
             Token::IntLit(i) => i.span(),
             Token::UIntLit(ui) => ui.span(),
@@ -44,6 +273,7 @@ impl Spanned for Token {
             Token::DocComment(dc) => dc.span(),
             Token::Delim(d) => d.span(),
             Token::Punc(p) => p.span(),
+            Token::Error(s, _) => *s,
             Token::EOF => Span::default(),
         }
     }
@@ -56,6 +286,14 @@ impl fmt::Display for Token {
             Token::StringLit(s) => {
                 write!(f, "{}", s)
             }
+            Token::ByteLit(b) => match b.clone().into_inner() {
+                Some(byte) => write!(f, "b'{}'", byte.escape_ascii()),
+                None => write!(f, "b''"),
+            },
+            Token::ByteStringLit(bs) => match bs.clone().into_inner() {
+                Some(bytes) => write!(f, "b\"{}\"", bytes.escape_ascii()),
+                None => write!(f, "b\"\""),
+            },
             Token::BoolLit(b) => write!(f, "`{}`", b),
             Token::IntLit(i) => write!(f, "`{}`", i),
             Token::UIntLit(ui) => write!(f, "`{}`", ui),
@@ -67,6 +305,7 @@ impl fmt::Display for Token {
             Token::DocComment(dc) => write!(f, "`{}`", dc.content),
             Token::Delim(d) => write!(f, "`{}`", d.clone().as_char()),
             Token::Punc(p) => write!(f, "`{}`", p.punc_kind.as_str()),
+            Token::Error(_, text) => write!(f, "invalid token `{}`", text),
             Token::EOF => write!(f, "end of file"),
         }
     }
@@ -74,45 +313,316 @@ impl fmt::Display for Token {
 
 #[derive(Debug, Clone)]
 pub struct TokenStream {
-    tokens: Vec<Option<Token>>,
+    tokens: Vec<Token>,
     span: Span,
 }
 
 impl TokenStream {
-    pub fn new(src: &str, tokens: Vec<Option<Token>>, start: usize, end: usize) -> Self {
+    pub fn new(src_id: SourceId, tokens: Vec<Token>, start: usize, end: usize) -> Self {
         Self {
             tokens,
-            span: Span::new(src, start, end),
+            span: Span::new(src_id, start, end),
         }
     }
 
-    pub fn tokens(&self) -> Vec<Token> {
-        self.tokens
-            .clone()
-            .into_iter()
-            .map(|t| t.expect("invalid token"))
-            .collect::<Vec<Token>>()
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Token> {
+        self.tokens.iter()
     }
 
     pub fn len(&self) -> usize {
         self.tokens.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Print the tokens as Feo source code, separated by single spaces where needed (or
+    /// for readability), and by newlines after line comments and around doc comments.
+    /// Lexing the output again gives the same sequence of tokens (apart from their spans),
+    /// e.g., to print the output of a macro expansion.
+    pub fn to_source(&self) -> String {
+        let mut source = String::new();
+        let mut prev: Option<(&Token, String)> = None;
+        let mut is_tuple_index = false;
+
+        for token in self.tokens.iter() {
+            let token_source = token.to_source();
+
+            if token_source.is_empty() {
+                continue;
+            }
+
+            if let Some((prev_token, prev_source)) = &prev {
+                if prev_token.ends_line() || matches!(token, Token::DocComment(_)) {
+                    source.push('\n');
+                } else if needs_space(prev_token, prev_source, &token_source, is_tuple_index)
+                    || wants_space(prev_token, token)
+                {
+                    source.push(' ');
+                }
+
+                // a number directly after a `.` is lexed as a tuple index
+                is_tuple_index =
+                    matches!(prev_token, Token::Punc(p) if p.punc_kind == PuncKind::FullStop);
+            }
+
+            source.push_str(&token_source);
+            prev = Some((token, token_source));
+        }
+
+        if prev.is_some_and(|(t, _)| t.ends_line()) {
+            source.push('\n');
+        }
+
+        source
+    }
+
+    /// Group the flat token stream into token trees, nesting the tokens between each pair of
+    /// matching delimiters. Mismatches are resolved the same way as in the lexer
+    /// (which reports them): a close delimiter that matches an enclosing group closes that
+    /// group (leaving the groups in between unclosed), otherwise it closes the innermost group.
+    /// A close delimiter with no open group is kept as a plain token.
+    pub fn token_trees(&self) -> Vec<TokenTree> {
+        let mut stack: Vec<(Delimiter, Vec<TokenTree>)> = Vec::new();
+        let mut trees: Vec<TokenTree> = Vec::new();
+
+        for token in self.tokens.iter() {
+            let delimiter = match token {
+                Token::Delim(d) => d,
+                _ => {
+                    push_tree(&mut stack, &mut trees, TokenTree::Token(token.clone()));
+                    continue;
+                }
+            };
+
+            match delimiter.delim.1 {
+                DelimOrientation::Open => stack.push((delimiter.clone(), Vec::new())),
+                DelimOrientation::Close => {
+                    let idx = match stack
+                        .iter()
+                        .rposition(|(d, _)| d.delim.0 == delimiter.delim.0)
+                    {
+                        Some(idx) => idx,
+                        None if !stack.is_empty() => stack.len() - 1,
+                        None => {
+                            push_tree(&mut stack, &mut trees, TokenTree::Token(token.clone()));
+                            continue;
+                        }
+                    };
+
+                    // groups opened inside the one being closed are left unclosed
+                    while stack.len() > idx + 1 {
+                        let (open, inner) = stack.pop().expect("stack is not empty");
+                        let group = DelimGroup::new(open, inner, None);
+                        push_tree(&mut stack, &mut trees, TokenTree::Group(group));
+                    }
+
+                    let (open, inner) = stack.pop().expect("stack is not empty");
+                    let group = DelimGroup::new(open, inner, Some(delimiter.clone()));
+                    push_tree(&mut stack, &mut trees, TokenTree::Group(group));
+                }
+            }
+        }
+
+        while let Some((open, inner)) = stack.pop() {
+            let group = DelimGroup::new(open, inner, None);
+            push_tree(&mut stack, &mut trees, TokenTree::Group(group));
+        }
+
+        trees
+    }
+}
+
+// add a tree to the innermost open group, or to the top level if there is none
+fn push_tree(
+    stack: &mut [(Delimiter, Vec<TokenTree>)],
+    trees: &mut Vec<TokenTree>,
+    tree: TokenTree,
+) {
+    match stack.last_mut() {
+        Some((_, inner)) => inner.push(tree),
+        None => trees.push(tree),
+    }
+}
+
+/// Either a single token or a group of token trees enclosed in a pair of delimiters
+#[derive(Debug, Clone)]
+pub enum TokenTree {
+    Token(Token),
+    Group(DelimGroup),
+}
+
+impl Spanned for TokenTree {
+    fn span(&self) -> Span {
+        match self {
+            TokenTree::Token(t) => t.span(),
+            TokenTree::Group(g) => g.span(),
+        }
+    }
+}
+
+/// Token trees enclosed by an open delimiter and its matching close delimiter
+/// (`close` is `None` if the group was never closed)
+#[derive(Debug, Clone)]
+pub struct DelimGroup {
+    pub open: Delimiter,
+    pub trees: Vec<TokenTree>,
+    pub close: Option<Delimiter>,
+}
+
+impl DelimGroup {
+    pub fn new(open: Delimiter, trees: Vec<TokenTree>, close: Option<Delimiter>) -> Self {
+        Self { open, trees, close }
+    }
+}
+
+impl Spanned for DelimGroup {
+    fn span(&self) -> Span {
+        let end = match (&self.close, self.trees.last()) {
+            (Some(c), _) => c.span(),
+            (None, Some(t)) => t.span(),
+            (None, None) => self.open.span(),
+        };
+
+        Span::join(self.open.span(), end)
+    }
 }
 
 impl Spanned for TokenStream {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
-impl Iterator for TokenStream {
+impl IntoIterator for TokenStream {
     type Item = Token;
+    type IntoIter = vec::IntoIter<Token>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.into_iter()
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(t) = self.tokens().into_iter().next() {
-            Some(t)
-        } else {
-            None
+impl<'a> IntoIterator for &'a TokenStream {
+    type Item = &'a Token;
+    type IntoIter = slice::Iter<'a, Token>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens.iter()
+    }
+}
+
+/// Kind of input between tokens that does not affect the meaning of a program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    Whitespace, // a run of whitespace chars other than newlines
+    Newline,    // `\n` or `\r\n`
+}
+
+/// Whitespace or a newline in the input, which is kept around a token in a lossless stream
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+impl Spanned for Trivia {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// A token with the trivia around it, and the span of its text in the input
+/// (like the token's own span, it covers all of the text, e.g., the quotes and any `b`
+/// prefix of a char literal).
+/// Trailing trivia runs up to and including the first newline after the token,
+/// and the rest of the trivia before the next token is that token's leading trivia.
+#[derive(Debug, Clone)]
+pub struct TriviaToken {
+    pub leading_trivia: Vec<Trivia>,
+    pub token: Token,
+    pub text: Span,
+    pub trailing_trivia: Vec<Trivia>,
+}
+
+impl Spanned for TriviaToken {
+    fn span(&self) -> Span {
+        self.text
+    }
+}
+
+/// Token stream that keeps all of the trivia in the input, so that the input can be rebuilt
+/// from it byte for byte (see `LosslessTokenStream::source_text()`)
+#[derive(Debug, Clone)]
+pub struct LosslessTokenStream {
+    tokens: Vec<TriviaToken>,
+    eof_trivia: Vec<Trivia>, // trivia after the trailing trivia of the last token
+    span: Span,
+}
+
+impl LosslessTokenStream {
+    pub fn new(
+        src_id: SourceId,
+        tokens: Vec<TriviaToken>,
+        eof_trivia: Vec<Trivia>,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        Self {
+            tokens,
+            eof_trivia,
+            span: Span::new(src_id, start, end),
         }
     }
+
+    pub fn tokens(&self) -> &[TriviaToken] {
+        &self.tokens
+    }
+
+    pub fn eof_trivia(&self) -> &[Trivia] {
+        &self.eof_trivia
+    }
+
+    /// Concatenate the text of every token and piece of trivia in `source_map`, in order,
+    /// which reproduces the input that the stream was lexed from.
+    pub fn source_text(&self, source_map: &SourceMap) -> String {
+        let mut buf = String::with_capacity(self.span.len());
+
+        for t in self.tokens.iter() {
+            t.leading_trivia
+                .iter()
+                .for_each(|tr| buf.push_str(tr.span.as_str(source_map)));
+
+            buf.push_str(t.text.as_str(source_map));
+
+            t.trailing_trivia
+                .iter()
+                .for_each(|tr| buf.push_str(tr.span.as_str(source_map)));
+        }
+
+        self.eof_trivia
+            .iter()
+            .for_each(|tr| buf.push_str(tr.span.as_str(source_map)));
+
+        buf
+    }
+
+    /// Drop the trivia and return the plain `TokenStream`.
+    pub fn into_token_stream(self) -> TokenStream {
+        TokenStream {
+            tokens: self.tokens.into_iter().map(|t| t.token).collect(),
+            span: self.span,
+        }
+    }
+}
+
+impl Spanned for LosslessTokenStream {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -15,6 +15,7 @@ use feo_types::{
     keyword::{Keyword, KeywordKind},
     literal::{FloatType, IntType, Literal, UIntType},
     punctuation::{PuncKind, Punctuation},
+    source_map::SourceId,
     span::{Position, Span},
     Identifier, U256,
 };
@@ -23,23 +24,23 @@ use crate::token::Token;
 
 pub trait Tokenize {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted>;
 }
 
 impl Tokenize for Comment {
-    fn tokenize<'a>(
-        src: &str,
+    fn tokenize(
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let comment = match content {
             _ if content.starts_with("//") => {
@@ -53,7 +54,7 @@ impl Tokenize for Comment {
             _ => {
                 let error = TypeError {
                     error_kind: TypeErrorKind::UnrecognizedCommentOpener,
-                    position: Position::new(src, start),
+                    position: Position::new(src_id, start),
                 };
 
                 return Err(handler.emit_err(CompilerError::Type(error)));
@@ -68,17 +69,17 @@ impl Tokenize for Comment {
 
 impl Tokenize for Delimiter {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = TypeError {
             error_kind: TypeErrorKind::UnrecognizedDelimiter,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         // convert `TypeErrorKind` to a `CompilerError::Type(TypeError)` and return `ErrorEmitted`
@@ -98,13 +99,13 @@ impl Tokenize for Delimiter {
 
 impl Tokenize for DocComment {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let mut inner_doc_comment = String::from("//");
         inner_doc_comment.push('!');
@@ -130,10 +131,22 @@ impl Tokenize for DocComment {
                 span,
             ),
 
+            _ if content.starts_with("/**") && content.ends_with("*/") => DocComment::new(
+                DocCommentKind::OuterBlockDocComment,
+                strip_block_doc_comment(&content[3..content.len() - 2]),
+                span,
+            ),
+
+            _ if content.starts_with("/*!") && content.ends_with("*/") => DocComment::new(
+                DocCommentKind::InnerBlockDocComment,
+                strip_block_doc_comment(&content[3..content.len() - 2]),
+                span,
+            ),
+
             _ => {
                 let error = TypeError {
                     error_kind: TypeErrorKind::UnrecognizedCommentOpener,
-                    position: Position::new(src, start),
+                    position: Position::new(src_id, start),
                 };
 
                 return Err(handler.emit_err(CompilerError::Type(error)));
@@ -146,19 +159,47 @@ impl Tokenize for DocComment {
     }
 }
 
+// strip the leading `*` "gutter" (and the space after it) from each line of a block doc comment
+// (excluding its opener and closer), e.g.:
+// ```
+// /**
+//  * Some documentation
+//  */
+// ```
+fn strip_block_doc_comment(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+
+            match trimmed.strip_prefix('*') {
+                Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+                None => trimmed,
+            }
+            .trim_end()
+        })
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
 // TODO: check for `TypeErrorKind::MismatchedTypeAnn` during parsing (ditto for literals)
 
 impl Tokenize for Identifier {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        _handler: &mut Handler,
+        _handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
-        let identifier = Identifier::new(content.to_string(), span);
+        let identifier = match content.strip_prefix("r#") {
+            Some(name) => Identifier::new_raw(name.to_string(), span),
+            None => Identifier::new(content.to_string(), span),
+        };
 
         let token = Token::Identifier(identifier);
 
@@ -168,17 +209,17 @@ impl Tokenize for Identifier {
 
 impl Tokenize for Keyword {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = TypeError {
             error_kind: TypeErrorKind::UnrecognizedKeyword,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         let keyword_kind = KeywordKind::from_str(content)
@@ -194,17 +235,17 @@ impl Tokenize for Keyword {
 
 impl Tokenize for Literal<char> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = ParserError {
             error_kind: ParserErrorKind::ParseCharError,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         let parsed = content
@@ -221,13 +262,13 @@ impl Tokenize for Literal<char> {
 
 impl Tokenize for Literal<String> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        _handler: &mut Handler,
+        _handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let literal = Literal::<String>::new(content.to_string(), span);
 
@@ -237,19 +278,79 @@ impl Tokenize for Literal<String> {
     }
 }
 
+// `content` holds one char per byte (i.e., every char is in the range `'\0'..='\u{FF}'`)
+impl Tokenize for Literal<u8> {
+    fn tokenize(
+        src_id: SourceId,
+        content: &str,
+        start: usize,
+        end: usize,
+        handler: &Handler,
+    ) -> Result<Option<Token>, ErrorEmitted> {
+        let span = Span::new(src_id, start, end);
+
+        let error = ParserError {
+            error_kind: ParserErrorKind::ParseByteError,
+            position: Position::new(src_id, start),
+        };
+
+        let parsed = content
+            .parse::<char>()
+            .ok()
+            .and_then(|c| u8::try_from(c).ok())
+            .ok_or_else(|| handler.emit_err(CompilerError::Parser(error)))?;
+
+        let literal = Literal::<u8>::new(parsed, span);
+
+        let token = Token::ByteLit(literal);
+
+        Ok(Some(token))
+    }
+}
+
+// `content` holds one char per byte (i.e., every char is in the range `'\0'..='\u{FF}'`)
+impl Tokenize for Literal<Vec<u8>> {
+    fn tokenize(
+        src_id: SourceId,
+        content: &str,
+        start: usize,
+        end: usize,
+        handler: &Handler,
+    ) -> Result<Option<Token>, ErrorEmitted> {
+        let span = Span::new(src_id, start, end);
+
+        let error = ParserError {
+            error_kind: ParserErrorKind::ParseByteError,
+            position: Position::new(src_id, start),
+        };
+
+        let parsed = content
+            .chars()
+            .map(u8::try_from)
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| handler.emit_err(CompilerError::Parser(error)))?;
+
+        let literal = Literal::<Vec<u8>>::new(parsed, span);
+
+        let token = Token::ByteStringLit(literal);
+
+        Ok(Some(token))
+    }
+}
+
 impl Tokenize for Literal<bool> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = ParserError {
             error_kind: ParserErrorKind::ParseBoolError,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         let parsed = content
@@ -266,23 +367,34 @@ impl Tokenize for Literal<bool> {
 
 impl Tokenize for Literal<IntType> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = ParserError {
             error_kind: ParserErrorKind::ParseIntError,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
-        let parsed = IntType::I64(
-            i64::from_str_radix(&content.split('_').collect::<Vec<&str>>().concat(), 10)
-                .map_err(|_| handler.emit_err(CompilerError::Parser(error)))?,
-        );
+        let (is_negative, radix, digits, suffix) = split_numeric(content);
+
+        let magnitude = parse_unsigned(digits, radix)
+            .filter(|m| *m <= U256::from(i64::MAX as u64) + 1)
+            .map(|m| m.low_u64() as i128)
+            .ok_or_else(|| handler.emit_err(CompilerError::Parser(error.clone())))?;
+
+        let value = if is_negative { -magnitude } else { magnitude };
+
+        let parsed = match suffix {
+            Some("i32") => i32::try_from(value).ok().map(IntType::I32),
+            Some("i64") | None => i64::try_from(value).ok().map(IntType::I64),
+            _ => None,
+        }
+        .ok_or_else(|| handler.emit_err(CompilerError::Parser(error)))?;
 
         let literal = Literal::<IntType>::new(parsed, span);
 
@@ -294,57 +406,57 @@ impl Tokenize for Literal<IntType> {
 
 impl Tokenize for Literal<UIntType> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let uint_error = ParserError {
             error_kind: ParserErrorKind::ParseUIntError,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         let u256_error = ParserError {
             error_kind: ParserErrorKind::ParseU256Error,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
-        let parsed = if content.starts_with("0x") {
-            let without_prefix = content.trim_start_matches("0x");
-
-            let content_as_hex_u256 = U256::from_str_radix(
-                &without_prefix.split('_').collect::<Vec<&str>>().concat(),
-                16,
-            )
-            .map_err(|_| handler.emit_err(CompilerError::Parser(u256_error)))?;
-
-            if content_as_hex_u256 > u64::MAX.into() {
-                panic!("Integer overflow: Input exceeds maximum `u64` value");
-            } else {
-                UIntType::U64(
-                    u64::from_str_radix(
-                        &without_prefix.split('_').collect::<Vec<&str>>().concat(),
-                        16,
-                    )
-                    .map_err(|_| handler.emit_err(CompilerError::Parser(uint_error)))?,
-                )
-            }
+        let (is_negative, radix, digits, suffix) = split_numeric(content);
+
+        if is_negative {
+            return Err(handler.emit_err(CompilerError::Parser(uint_error)));
+        }
+
+        // an unsuffixed literal may be promoted to a `U256`, so report its overflow as such
+        let parse_error = if suffix.is_some() {
+            uint_error.clone()
         } else {
-            let content_as_dec_u256 =
-                U256::from_str_radix(&content.split('_').collect::<Vec<&str>>().concat(), 10)
-                    .map_err(|_| handler.emit_err(CompilerError::Parser(u256_error)))?;
-
-            if content_as_dec_u256 > u64::MAX.into() {
-                panic!("Integer overflow: Input exceeds maximum `u64` value");
-            } else {
-                UIntType::U64(
-                    u64::from_str_radix(&content.split('_').collect::<Vec<&str>>().concat(), 10)
-                        .map_err(|_| handler.emit_err(CompilerError::Parser(uint_error)))?,
-                )
+            u256_error
+        };
+
+        let value = parse_unsigned(digits, radix)
+            .ok_or_else(|| handler.emit_err(CompilerError::Parser(parse_error)))?;
+
+        let fits = |max: u64| value <= U256::from(max);
+
+        let parsed = match suffix {
+            Some("u8") if fits(u8::MAX.into()) => UIntType::U8(value.low_u64() as u8),
+            Some("u16") if fits(u16::MAX.into()) => UIntType::U16(value.low_u64() as u16),
+            Some("u32") if fits(u32::MAX.into()) => UIntType::U32(value.low_u64() as u32),
+            Some("u64") if fits(u64::MAX) => UIntType::U64(value.low_u64()),
+            None if fits(u64::MAX) => UIntType::U64(value.low_u64()),
+
+            // an unsuffixed literal that does not fit into a `u64` (e.g., a token amount
+            // like `1_000_000e18`) is promoted to a `U256`
+            None => {
+                let literal = Literal::<U256>::new(value, span);
+                return Ok(Some(Token::U256Lit(literal)));
             }
+
+            _ => return Err(handler.emit_err(CompilerError::Parser(uint_error))),
         };
 
         let literal = Literal::<UIntType>::new(parsed, span);
@@ -357,33 +469,27 @@ impl Tokenize for Literal<UIntType> {
 
 impl Tokenize for Literal<U256> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = ParserError {
             error_kind: ParserErrorKind::ParseU256Error,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
-        let content = content.to_lowercase();
+        let (is_negative, radix, digits, suffix) = split_numeric(content);
 
-        let parsed = if content.starts_with("0x") {
-            let without_prefix = content.trim_start_matches("0x");
+        if is_negative || !matches!(suffix, Some("u256") | None) {
+            return Err(handler.emit_err(CompilerError::Parser(error)));
+        }
 
-            U256::from_str_radix(
-                &without_prefix.split('_').collect::<Vec<&str>>().concat(),
-                16,
-            )
-            .map_err(|_| handler.emit_err(CompilerError::Parser(error)))?
-        } else {
-            U256::from_str_radix(&content.split('_').collect::<Vec<&str>>().concat(), 10)
-                .map_err(|_| handler.emit_err(CompilerError::Parser(error)))?
-        };
+        let parsed = parse_unsigned(digits, radix)
+            .ok_or_else(|| handler.emit_err(CompilerError::Parser(error)))?;
 
         let literal = Literal::<U256>::new(parsed, span);
 
@@ -395,24 +501,38 @@ impl Tokenize for Literal<U256> {
 
 impl Tokenize for Literal<FloatType> {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = ParserError {
             error_kind: ParserErrorKind::ParseFloatError,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
-        let parsed = FloatType::F64(
-            content
+        let (is_negative, radix, digits, suffix) = split_numeric(content);
+
+        let sign = if is_negative { "-" } else { "" };
+        let number = format!("{}{}", sign, digits.replace('_', ""));
+
+        let parsed = match (radix, suffix) {
+            (10, Some("f32")) => number
+                .parse::<f32>()
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(FloatType::F32),
+            (10, Some("f64") | None) => number
                 .parse::<f64>()
-                .map_err(|_| handler.emit_err(CompilerError::Parser(error)))?,
-        );
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(FloatType::F64),
+            _ => None,
+        }
+        .ok_or_else(|| handler.emit_err(CompilerError::Parser(error)))?;
 
         let literal = Literal::<FloatType>::new(parsed, span);
 
@@ -422,19 +542,86 @@ impl Tokenize for Literal<FloatType> {
     }
 }
 
+// split a numeric literal into its sign, radix, digits (after the radix prefix) and type suffix,
+// e.g., `-0xff_u64` -> `(true, 16, "ff_", Some("u64"))`
+fn split_numeric(content: &str) -> (bool, u32, &str, Option<&str>) {
+    let (is_negative, unsigned) = match content.strip_prefix('-') {
+        Some(c) => (true, c),
+        None => (false, content),
+    };
+
+    let (radix, body) = match unsigned.get(..2).map(|p| p.to_lowercase()).as_deref() {
+        Some("0x") => (16, &unsigned[2..]),
+        Some("0o") => (8, &unsigned[2..]),
+        Some("0b") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+
+    // `f` is a hexadecimal digit, so hexadecimal literals cannot take a float suffix
+    let suffix_start = if radix == 16 {
+        body.find(['u', 'i'])
+    } else {
+        body.find(['u', 'i', 'f'])
+    };
+
+    match suffix_start {
+        Some(i) => (is_negative, radix, &body[..i], Some(&body[i..])),
+        None => (is_negative, radix, body, None),
+    }
+}
+
+// parse the digits of an unsigned integer literal (ignoring `_` separators), including
+// a decimal exponent (e.g., `1_000e18`)
+// return `None` if the digits are invalid for the radix or the value overflows a `U256`
+fn parse_unsigned(digits: &str, radix: u32) -> Option<U256> {
+    let digits = digits.replace('_', "");
+
+    let (mantissa, exponent) = match digits.find(['e', 'E']) {
+        Some(i) if radix == 10 => {
+            let exponent = digits[i + 1..]
+                .trim_start_matches('+')
+                .parse::<u32>()
+                .ok()?;
+            (&digits[..i], exponent)
+        }
+        _ => (digits.as_str(), 0),
+    };
+
+    if mantissa.is_empty() {
+        return None;
+    }
+
+    let mut value = U256::zero();
+
+    for c in mantissa.chars() {
+        let digit = c.to_digit(radix)?;
+        value = value.checked_mul(radix.into())?.checked_add(digit.into())?;
+    }
+
+    if value.is_zero() {
+        return Some(value);
+    }
+
+    for _ in 0..exponent {
+        value = value.checked_mul(10.into())?;
+    }
+
+    Some(value)
+}
+
 impl Tokenize for Punctuation {
     fn tokenize(
-        src: &str,
+        src_id: SourceId,
         content: &str,
         start: usize,
         end: usize,
-        handler: &mut Handler,
+        handler: &Handler,
     ) -> Result<Option<Token>, ErrorEmitted> {
-        let span = Span::new(src, start, end);
+        let span = Span::new(src_id, start, end);
 
         let error = TypeError {
             error_kind: TypeErrorKind::UnexpectedPunctuation,
-            position: Position::new(src, start),
+            position: Position::new(src_id, start),
         };
 
         let punc_kind = PuncKind::from_str(content)
@@ -0,0 +1,315 @@
+use std::fmt;
+
+use feo_types::{
+    source_map::SourceMap,
+    span::{Position, Span},
+};
+
+use crate::{
+    error::CompilerError,
+    lex_error::{LexError, LexErrorKind},
+    lint::Lint,
+    parser_error::{ParserError, ParserErrorKind},
+    suggestion::{Applicability, Suggestion},
+    type_error::TypeError,
+    warning::{CompilerWarning, WarningKind},
+};
+
+/// How serious a `Diagnostic` is
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
+
+/// Span in the source code with a message about it (which may be empty)
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+
+impl Label {
+    pub fn new(span: Span, message: &str) -> Self {
+        Self {
+            span,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Message to the user about their code, with the information needed to report it,
+/// i.e., the labelled spans that it is about, plus any notes and help text.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub primary: Label,
+    pub secondary: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+    /// Edits to the source code that fix the problem
+    pub suggestions: Vec<Suggestion>,
+    /// Error that the diagnostic was created from, if any (e.g., to match on its kind)
+    pub error: Option<CompilerError>,
+    /// Warning that the diagnostic was created from, if any
+    /// (its severity is `Error` if the lint level of the warning is `deny` or `forbid`)
+    pub warning: Option<CompilerWarning>,
+    /// Lint that reported the warning, if any
+    /// (lints are named rather than numbered, so they have no `code`)
+    pub lint: Option<&'static Lint>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: &str, span: Span) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.to_string(),
+            primary: Label::new(span, ""),
+            secondary: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            suggestions: Vec::new(),
+            error: None,
+            warning: None,
+            lint: None,
+        }
+    }
+
+    pub fn error(message: &str, span: Span) -> Self {
+        Diagnostic::new(Severity::Error, message, span)
+    }
+
+    pub fn warning(message: &str, span: Span) -> Self {
+        Diagnostic::new(Severity::Warning, message, span)
+    }
+
+    pub fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Set the message of the primary label.
+    pub fn with_primary_label(mut self, message: &str) -> Self {
+        self.primary.message = message.to_string();
+        self
+    }
+
+    /// Add a secondary label.
+    pub fn with_label(mut self, span: Span, message: &str) -> Self {
+        self.secondary.push(Label::new(span, message));
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
+    pub fn span(&self) -> Span {
+        self.primary.span
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Return the one-based line and column of the start of the primary span
+    /// in its file in `source_map`.
+    pub fn line_col(&self, source_map: &SourceMap) -> (usize, usize) {
+        self.primary.span.start_pos().line_col(source_map)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// empty span at a position, as errors only record the position where they occurred
+// (the renderer underlines the char at the position)
+fn position_span(position: &Position) -> Span {
+    Span::new(position.source_id(), position.pos(), position.pos())
+}
+
+// span of a char that is known to be at a position (e.g., a delimiter)
+fn char_span(position: &Position, c: char) -> Span {
+    let pos = position.pos();
+    Span::new(position.source_id(), pos, pos + c.len_utf8())
+}
+
+// span of a word at a position (e.g., to replace it)
+fn word_span(position: &Position, word: &str) -> Span {
+    let pos = position.pos();
+    Span::new(position.source_id(), pos, pos + word.len())
+}
+
+impl From<LexError> for Diagnostic {
+    fn from(error: LexError) -> Self {
+        let span = position_span(&error.position);
+
+        let diagnostic = match &error.error_kind {
+            LexErrorKind::MismatchedDelimiters {
+                open,
+                open_position,
+                close,
+            } => Diagnostic::error(
+                &error.error_kind.to_string(),
+                char_span(&error.position, *close),
+            )
+            .with_primary_label(&format!("`{}` does not match `{}`", close, open))
+            .with_label(
+                char_span(open_position, *open),
+                &format!("`{}` opened here", open),
+            ),
+            LexErrorKind::InvalidChar(c) => Diagnostic::error(
+                &error.error_kind.to_string(),
+                char_span(&error.position, *c),
+            ),
+            LexErrorKind::UnclosedBlockComment => {
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_primary_label("block comment opened here")
+                    .with_note("block comments can be nested, and each `/*` needs its own `*/`")
+            }
+            LexErrorKind::InvalidRawIdentifier => {
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_help("remove the `r#` prefix")
+            }
+            _ => Diagnostic::error(&error.error_kind.to_string(), span),
+        };
+
+        Diagnostic {
+            code: Some(error.error_kind.code().to_string()),
+            error: Some(CompilerError::Lex(error)),
+            ..diagnostic
+        }
+    }
+}
+
+impl From<ParserError> for Diagnostic {
+    fn from(error: ParserError) -> Self {
+        let span = position_span(&error.position);
+
+        let diagnostic = match &error.error_kind {
+            ParserErrorKind::UnexpectedToken { expected, .. } => {
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_primary_label(&format!("expected {}", expected))
+            }
+            ParserErrorKind::MissingDelimiter { delim } => {
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_primary_label(&format!("expected `{}`", delim))
+            }
+            ParserErrorKind::ForeignKeyword { found, replacement } => {
+                let span = word_span(&error.position, found);
+
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_primary_label(&format!("use `{}` instead", replacement))
+                    .with_suggestion(Suggestion::new(
+                        span,
+                        replacement,
+                        Applicability::MachineApplicable,
+                    ))
+            }
+            ParserErrorKind::UnknownWord {
+                found,
+                suggestion: Some(suggestion),
+            } => {
+                let span = word_span(&error.position, found);
+
+                Diagnostic::error(&error.error_kind.to_string(), span)
+                    .with_primary_label(&format!("did you mean `{}`?", suggestion))
+                    .with_suggestion(Suggestion::new(
+                        span,
+                        suggestion,
+                        Applicability::MaybeIncorrect,
+                    ))
+            }
+            ParserErrorKind::UnknownWord { found, .. } => Diagnostic::error(
+                &error.error_kind.to_string(),
+                word_span(&error.position, found),
+            )
+            .with_primary_label("expected an item or statement"),
+            _ => Diagnostic::error(&error.error_kind.to_string(), span),
+        };
+
+        Diagnostic {
+            code: Some(error.error_kind.code().to_string()),
+            error: Some(CompilerError::Parser(error)),
+            ..diagnostic
+        }
+    }
+}
+
+impl From<TypeError> for Diagnostic {
+    fn from(error: TypeError) -> Self {
+        let span = position_span(&error.position);
+
+        Diagnostic {
+            code: Some(error.error_kind.code().to_string()),
+            error: Some(CompilerError::Type(error.clone())),
+            ..Diagnostic::error(&error.error_kind.to_string(), span)
+        }
+    }
+}
+
+impl From<CompilerError> for Diagnostic {
+    fn from(error: CompilerError) -> Self {
+        match error {
+            CompilerError::Lex(l) => Diagnostic::from(l),
+            CompilerError::Parser(p) => Diagnostic::from(p),
+            CompilerError::Type(t) => Diagnostic::from(t),
+            CompilerError::UnexpectedError => Diagnostic {
+                code: Some(error.code().to_string()),
+                error: Some(CompilerError::UnexpectedError),
+                ..Diagnostic::error("unexpected error", Span::default())
+            },
+        }
+    }
+}
+
+impl From<CompilerWarning> for Diagnostic {
+    fn from(warning: CompilerWarning) -> Self {
+        let span = warning.span;
+
+        let diagnostic = match &warning.warning_kind {
+            WarningKind::UnnecessaryRawIdentifier { name } => {
+                Diagnostic::warning(&warning.warning_kind.to_string(), span).with_suggestion(
+                    Suggestion::new(span, name, Applicability::MachineApplicable),
+                )
+            }
+            WarningKind::UnknownLint { .. } => {
+                Diagnostic::warning(&warning.warning_kind.to_string(), span)
+            }
+        };
+
+        Diagnostic {
+            lint: Some(warning.warning_kind.lint()),
+            warning: Some(warning),
+            ..diagnostic
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt;
 
-use feo_types::span::Position;
+use feo_types::{source_map::SourceMap, span::Position};
 
 use crate::{
     lex_error::LexError,
@@ -20,12 +20,24 @@ pub enum CompilerError {
 }
 
 impl CompilerError {
-    pub fn line_col(&self) -> (usize, usize) {
+    /// Return the one-based line and column where the error occurred, in its file in
+    /// `source_map`.
+    pub fn line_col(&self, source_map: &SourceMap) -> (usize, usize) {
         match self {
-            CompilerError::Lex(l) => l.position.line_col(),
-            CompilerError::Parser(p) => p.position.line_col(),
-            CompilerError::Type(t) => t.position.line_col(),
-            CompilerError::UnexpectedError => Position::default().line_col(),
+            CompilerError::Lex(l) => l.position.line_col(source_map),
+            CompilerError::Parser(p) => p.position.line_col(source_map),
+            CompilerError::Type(t) => t.position.line_col(source_map),
+            CompilerError::UnexpectedError => Position::default().line_col(source_map),
+        }
+    }
+
+    /// Return the stable code of the error kind (`E0001` for an unexpected error).
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompilerError::Lex(l) => l.error_kind.code(),
+            CompilerError::Parser(p) => p.error_kind.code(),
+            CompilerError::Type(t) => t.error_kind.code(),
+            CompilerError::UnexpectedError => "E0001",
         }
     }
 
@@ -56,12 +68,6 @@ impl Error for CompilerError {
 
 impl fmt::Display for CompilerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}:{}",
-            self.error_kind(),
-            self.line_col().0,
-            self.line_col().1
-        )
+        write!(f, "{}", self.error_kind())
     }
 }
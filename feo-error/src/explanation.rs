@@ -0,0 +1,670 @@
+/// Return the long-form explanation of an error code (e.g., `E0101`), or `None` if the code
+/// does not exist.
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+/// Error codes and their explanations, each with an example of code that causes the error
+/// and how to fix it.
+/// Codes are grouped by compiler stage: `E00xx` (general), `E01xx` (lexer), `E02xx` (parser)
+/// and `E03xx` (types).
+pub const EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        "E0001",
+        r##"An unexpected error occurred in the compiler.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0100",
+        r##"An unknown error occurred while lexing the source code.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0101",
+        r##"A char literal is empty.
+
+Erroneous code example:
+
+```feo
+let c = '';
+```
+
+A char literal must contain exactly one char. Use a string literal for empty text:
+
+```feo
+let c = 'a';
+let s = "";
+```
+"##,
+    ),
+    (
+        "E0102",
+        r##"A char literal contains more than one char.
+
+Erroneous code example:
+
+```feo
+let c = 'ab';
+```
+
+A char literal must contain exactly one char. Use a string literal for longer text:
+
+```feo
+let c = 'a';
+let s = "ab";
+```
+"##,
+    ),
+    (
+        "E0103",
+        r##"A char literal was expected, but the input ended.
+
+Erroneous code example:
+
+```feo
+let c = '
+```
+
+Add the char and the closing single quote:
+
+```feo
+let c = 'a';
+```
+"##,
+    ),
+    (
+        "E0104",
+        r##"A char or byte literal is missing its closing single quote.
+
+Erroneous code example:
+
+```feo
+let c = 'a;
+```
+
+Add the closing single quote:
+
+```feo
+let c = 'a';
+```
+"##,
+    ),
+    (
+        "E0105",
+        r##"A string literal is missing its closing double quote.
+
+Erroneous code example:
+
+```feo
+let s = "hello;
+```
+
+Add the closing double quote:
+
+```feo
+let s = "hello";
+```
+
+A double quote inside a string must be escaped (`\"`).
+"##,
+    ),
+    (
+        "E0106",
+        r##"An escape sequence in a char or string literal is not recognized.
+
+Erroneous code example:
+
+```feo
+let s = "C:\Users";
+```
+
+The valid escape sequences are `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\xHH` (an ASCII
+byte) and `\u{HHHHHH}` (a Unicode code point, not allowed in byte literals).
+Escape the backslash itself to include it in the text:
+
+```feo
+let s = "C:\\Users";
+```
+"##,
+    ),
+    (
+        "E0107",
+        r##"A backslash in a char or string literal is not followed by an escape sequence.
+
+Erroneous code example:
+
+```feo
+let c = '\
+```
+
+Complete the escape sequence, or escape the backslash itself:
+
+```feo
+let c = '\\';
+```
+"##,
+    ),
+    (
+        "E0108",
+        r##"A byte literal or byte string literal contains a non-ASCII char.
+
+Erroneous code example:
+
+```feo
+let b = b'é';
+```
+
+Byte literals can only contain ASCII chars. Use a `\xHH` escape for other byte values:
+
+```feo
+let b = b'\xe9';
+```
+"##,
+    ),
+    (
+        "E0109",
+        r##"A block comment is never closed.
+
+Erroneous code example:
+
+```feo
+/* outer /* inner */
+let x = 1;
+```
+
+Block comments can be nested, so each `/*` needs its own `*/`:
+
+```feo
+/* outer /* inner */ */
+let x = 1;
+```
+"##,
+    ),
+    (
+        "E0110",
+        r##"An open delimiter is never closed.
+
+Erroneous code example:
+
+```feo
+func foo() {
+    bar(1, 2);
+```
+
+Add the matching close delimiter:
+
+```feo
+func foo() {
+    bar(1, 2);
+}
+```
+"##,
+    ),
+    (
+        "E0111",
+        r##"A close delimiter has no matching open delimiter.
+
+Erroneous code example:
+
+```feo
+let x = foo(1));
+```
+
+Remove the close delimiter, or add the open delimiter that it closes:
+
+```feo
+let x = foo(1);
+```
+"##,
+    ),
+    (
+        "E0112",
+        r##"A numeric literal has a type suffix that is not recognized.
+
+Erroneous code example:
+
+```feo
+let x = 7u7;
+```
+
+The valid suffixes are `u8`, `u16`, `u32`, `u64`, `u256`, `i32`, `i64`, `f32` and `f64`:
+
+```feo
+let x = 7u8;
+```
+"##,
+    ),
+    (
+        "E0113",
+        r##"`self`, `Self` or `super` is used as a raw identifier.
+
+Erroneous code example:
+
+```feo
+let r#self = 1;
+```
+
+These keywords cannot be used as identifiers, even with the `r#` prefix. Use another name:
+
+```feo
+let this = 1;
+```
+"##,
+    ),
+    (
+        "E0114",
+        r##"A close delimiter does not match the most recent open delimiter.
+
+Erroneous code example:
+
+```feo
+let x = foo(a[0);
+```
+
+Close the delimiters in the reverse order that they were opened:
+
+```feo
+let x = foo(a[0]);
+```
+"##,
+    ),
+    (
+        "E0115",
+        r##"The source code contains a char that is not valid in Feo outside of a comment or
+a literal.
+
+Erroneous code example:
+
+```feo
+let x = 1 € 2;
+```
+
+Remove the char, or move it into a string literal:
+
+```feo
+let x = 1 + 2;
+let s = "€";
+```
+"##,
+    ),
+    (
+        "E0200",
+        r##"An unknown error occurred while parsing the source code.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0201",
+        r##"A char literal could not be converted to a `char`.
+
+This is a bug in the compiler rather than in your code, as the lexer checks literals
+before they are converted. Please report it, together with the code that triggered it.
+"##,
+    ),
+    (
+        "E0202",
+        r##"A byte literal or byte string literal could not be converted to bytes.
+
+This is a bug in the compiler rather than in your code, as the lexer checks literals
+before they are converted. Please report it, together with the code that triggered it.
+"##,
+    ),
+    (
+        "E0203",
+        r##"A bool literal could not be converted to a `bool`.
+
+This is a bug in the compiler rather than in your code, as the lexer checks literals
+before they are converted. Please report it, together with the code that triggered it.
+"##,
+    ),
+    (
+        "E0204",
+        r##"A signed integer literal could not be parsed, or does not fit into its type.
+
+Erroneous code example:
+
+```feo
+let x = 3000000000i32;
+```
+
+The value of an `i32` must be between `-2147483648` and `2147483647`, and the value of
+an `i64` must be between `-9223372036854775808` and `9223372036854775807`.
+Use a larger type:
+
+```feo
+let x = 3000000000i64;
+```
+"##,
+    ),
+    (
+        "E0205",
+        r##"An unsigned integer literal could not be parsed, or does not fit into its type.
+
+Erroneous code example:
+
+```feo
+let x = 300u8;
+let y = -1u64;
+```
+
+Unsigned integers cannot be negative, and the value must fit into the type (e.g., at
+most `255` for a `u8`). Use a larger or signed type:
+
+```feo
+let x = 300u16;
+let y = -1i64;
+```
+"##,
+    ),
+    (
+        "E0206",
+        r##"A `u256` literal could not be parsed, or does not fit into a `u256`.
+
+Erroneous code example:
+
+```feo
+let x = 1e80u256;
+```
+
+The value of a `u256` must be less than `2^256` (about `1.16e77`).
+"##,
+    ),
+    (
+        "E0207",
+        r##"A float literal could not be parsed.
+
+Erroneous code example:
+
+```feo
+let x = 0x1f64;
+```
+
+Float literals must be decimal, and their value must be finite (e.g., `1e400f64` is too
+large). Hexadecimal literals cannot take a float suffix, as `f` is a hexadecimal digit:
+
+```feo
+let x = 31.0f64;
+```
+"##,
+    ),
+    (
+        "E0208",
+        r##"A type annotation could not be parsed.
+
+Erroneous code example:
+
+```feo
+let x: = 1;
+```
+
+Add the type after the `:`:
+
+```feo
+let x: u64 = 1;
+```
+"##,
+    ),
+    (
+        "E0209",
+        r##"The position of a char in the source code could not be found.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0210",
+        r##"A delimiter is missing.
+
+Erroneous code example:
+
+```feo
+func foo( {
+}
+```
+
+Add the missing delimiter:
+
+```feo
+func foo() {
+}
+```
+"##,
+    ),
+    (
+        "E0211",
+        r##"A keyword is used where it is not allowed.
+
+Erroneous code example:
+
+```feo
+let let = 1;
+```
+
+Keywords cannot be used as identifiers. Use another name:
+
+```feo
+let value = 1;
+```
+"##,
+    ),
+    (
+        "E0212",
+        r##"A punctuation mark is used where it is not allowed.
+
+Erroneous code example:
+
+```feo
+let x = 1 +;
+```
+
+Remove the punctuation, or complete the expression:
+
+```feo
+let x = 1 + 2;
+```
+"##,
+    ),
+    (
+        "E0213",
+        r##"A token is used where it is not allowed.
+
+Erroneous code example:
+
+```feo
+let x = t.0u8;
+```
+
+A tuple index must be an unsuffixed integer:
+
+```feo
+let x = t.0;
+```
+"##,
+    ),
+    (
+        "E0214",
+        r##"The parser found a different token from the one that it expected.
+
+Erroneous code example:
+
+```feo
+let x = 1
+let y = 2;
+```
+
+The error message says which token was expected (here, a `;` at the end of the first
+statement):
+
+```feo
+let x = 1;
+let y = 2;
+```
+"##,
+    ),
+    (
+        "E0215",
+        r##"The input ended where a token was expected.
+
+Erroneous code example:
+
+```feo
+let x =
+```
+
+Complete the code:
+
+```feo
+let x = 1;
+```
+"##,
+    ),
+    (
+        "E0216",
+        r##"A keyword from another language is used instead of its Feo equivalent.
+
+Erroneous code example:
+
+```feo
+use package::token::Token;
+
+fn transfer(to: Address) {}
+```
+
+Feo uses `func` (for `fn` and `function`), `import` (for `use`), `module` (for `mod`) and
+`package::` (for `crate::`):
+
+```feo
+import package::token::Token;
+
+func transfer(to: Address) {}
+```
+"##,
+    ),
+    (
+        "E0217",
+        r##"A word that is not a keyword is used where an item or statement was expected.
+
+Erroneous code example:
+
+```feo
+strcut Point {
+    x: u64,
+}
+```
+
+This is usually a misspelled keyword (the error message suggests the closest keyword or
+declared name, if there is one):
+
+```feo
+struct Point {
+    x: u64,
+}
+```
+"##,
+    ),
+    (
+        "E0300",
+        r##"An unknown type error occurred.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0301",
+        r##"A comment opener is not recognized.
+
+Comments open with `//` (line comments), `///` or `//!` (doc comments), `/*` (block
+comments), or `/**` or `/*!` (block doc comments):
+
+```feo
+// line comment
+/// doc comment
+/* block comment */
+```
+"##,
+    ),
+    (
+        "E0302",
+        r##"A delimiter is not recognized.
+
+The delimiters are parentheses (`(` and `)`), brackets (`[` and `]`) and braces (`{`
+and `}`):
+
+```feo
+let x = foo(a[0], { 1 });
+```
+"##,
+    ),
+    (
+        "E0303",
+        r##"A keyword is not recognized.
+
+This is a bug in the compiler rather than in your code. Please report it, together with
+the code that triggered it.
+"##,
+    ),
+    (
+        "E0304",
+        r##"A punctuation mark is not recognized.
+
+Erroneous code example:
+
+```feo
+let x = 1 $ 2;
+```
+
+Use an operator that exists in Feo:
+
+```feo
+let x = 1 + 2;
+```
+"##,
+    ),
+    (
+        "E0305",
+        r##"A value does not have the type that it is expected to have.
+
+Erroneous code example:
+
+```feo
+let x: u64 = true;
+```
+
+Change the value or the type so that they match:
+
+```feo
+let x: bool = true;
+```
+"##,
+    ),
+    (
+        "E0306",
+        r##"A value was expected, but not found.
+
+Erroneous code example:
+
+```feo
+let x: u64 = ;
+```
+
+Add the value:
+
+```feo
+let x: u64 = 1;
+```
+"##,
+    ),
+];
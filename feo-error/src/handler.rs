@@ -1,9 +1,21 @@
-use core::cell::RefCell;
+use std::{cell::RefCell, collections::HashSet, ops::Deref, rc::Rc};
 
-use crate::{error::CompilerError, warning::CompilerWarning};
+use feo_types::span::Span;
+
+use crate::{
+    diagnostic::{Diagnostic, Severity},
+    error::CompilerError,
+    lint::{self, Lint, LintLevel, LintOverride},
+    warning::{CompilerWarning, WarningKind},
+};
 
 /// Handler that contains information to be emitted.
 /// Uses `RefCell` around the `HandlerInner` to avoid excessive `&mut`
+/// Clones are separate copies; use a `SharedHandler` to log to the same `Handler` from
+/// several places.
+/// Diagnostics are kept in the order that they were emitted, and only sorted by file and offset
+/// when they are taken out to be rendered (see `diagnostics()`). Identical diagnostics at the
+/// same span are only kept once.
 #[derive(Default, Debug, Clone)]
 pub struct Handler {
     inner: RefCell<HandlerInner>,
@@ -12,23 +24,285 @@ pub struct Handler {
 /// Inner struct that contains actual data
 #[derive(Default, Debug, Clone)]
 struct HandlerInner {
-    errors: Vec<CompilerError>,
+    errors: Vec<Diagnostic>,
     warnings: Vec<CompilerWarning>,
+    error_keys: HashSet<DiagnosticKey>, // to drop duplicate errors
+    warning_keys: HashSet<(WarningKind, Span)>, // to drop duplicate warnings
+    lint_overrides: Vec<LintOverride>,
+    warnings_as_errors: bool,
+    error_limit: Option<usize>,
+    num_dropped_errors: usize,
 }
 
 impl Handler {
     pub fn emit_err(&self, err: CompilerError) -> ErrorEmitted {
-        self.inner.borrow_mut().errors.push(err);
+        self.emit_diagnostic(Diagnostic::from(err))
+    }
+
+    /// Emit an error diagnostic that is built up directly (e.g., with extra labels or help text),
+    /// rather than converted from a `CompilerError`.
+    /// The diagnostic is dropped if an identical one was already emitted, or if the error
+    /// limit has been reached.
+    pub fn emit_diagnostic(&self, diagnostic: Diagnostic) -> ErrorEmitted {
+        let mut inner = self.inner.borrow_mut();
+
+        let key = DiagnosticKey::new(&diagnostic);
+
+        if !inner.error_keys.contains(&key) {
+            if inner.error_limit.is_some_and(|l| inner.errors.len() >= l) {
+                inner.num_dropped_errors += 1;
+            } else {
+                inner.error_keys.insert(key);
+                inner.errors.push(diagnostic);
+            }
+        }
+
         ErrorEmitted::emit()
     }
 
+    /// Emit a warning, whose level is decided when the diagnostics are taken out of the
+    /// `Handler` (i.e., after the lint levels of the items that it is in have been set).
+    /// The warning is dropped if an identical one was already emitted.
     pub fn emit_warn(&self, warn: CompilerWarning) {
-        self.inner.borrow_mut().warnings.push(warn)
+        let mut inner = self.inner.borrow_mut();
+
+        if inner
+            .warning_keys
+            .insert((warn.warning_kind.clone(), warn.span))
+        {
+            inner.warnings.push(warn)
+        }
+    }
+
+    /// Set the level of a lint in the source code covered by a span
+    /// (e.g., for an item with a `#[deny(..)]` attribute).
+    pub fn set_lint_level(&self, span: Span, lint: &'static Lint, level: LintLevel) {
+        self.inner
+            .borrow_mut()
+            .lint_overrides
+            .push(LintOverride { span, lint, level });
+    }
+
+    /// Report warnings as errors (unless their lint is allowed).
+    pub fn set_warnings_as_errors(&self, warnings_as_errors: bool) {
+        self.inner.borrow_mut().warnings_as_errors = warnings_as_errors;
+    }
+
+    /// Stop storing errors after `limit` of them (`None` for no limit). Errors after the limit
+    /// are counted but dropped, so that a pass can abort once `error_limit_reached()`.
+    pub fn set_error_limit(&self, limit: Option<usize>) {
+        self.inner.borrow_mut().error_limit = limit;
+    }
+
+    /// Check whether as many errors as the error limit have been emitted
+    /// (not counting warnings, whose level is only known later).
+    pub fn error_limit_reached(&self) -> bool {
+        let inner = self.inner.borrow();
+        inner.error_limit.is_some_and(|l| inner.errors.len() >= l)
     }
 
-    pub fn get_inner(self) -> (Vec<CompilerError>, Vec<CompilerWarning>) {
-        let inner = self.inner.into_inner();
-        (inner.errors, inner.warnings)
+    /// Return the number of errors that were dropped because the error limit was reached
+    /// (not counting denied warnings).
+    pub fn num_dropped_errors(&self) -> usize {
+        self.inner.borrow().num_dropped_errors
+    }
+
+    /// Return the errors, including warnings of lints that are denied (or all warnings,
+    /// if warnings are treated as errors), in the order that they were emitted
+    /// (denied warnings come after the other errors, up to the error limit).
+    pub fn errors(&self) -> Vec<Diagnostic> {
+        let inner = self.inner.borrow();
+
+        let mut errors = inner.errors.clone();
+        errors.extend(inner.warning_diagnostics(true));
+
+        if let Some(limit) = inner.error_limit {
+            errors.truncate(limit);
+        }
+
+        errors
+    }
+
+    /// Return the warnings, leaving out warnings of lints that are allowed, in the order
+    /// that they were emitted.
+    pub fn warnings(&self) -> Vec<Diagnostic> {
+        self.inner.borrow().warning_diagnostics(false).collect()
+    }
+
+    /// Check whether any errors were emitted, including warnings of lints that are denied
+    /// (without building their diagnostics).
+    pub fn has_errors(&self) -> bool {
+        let inner = self.inner.borrow();
+
+        !inner.errors.is_empty()
+            || inner
+                .warnings
+                .iter()
+                .any(|w| inner.is_error_level(inner.warning_level(w)))
+    }
+
+    /// Return an empty `Handler` with the same settings (e.g., lint levels and the number of
+    /// errors left before the error limit), e.g., for a pass that runs in parallel with others
+    /// and is merged back afterwards.
+    pub fn fork(&self) -> Handler {
+        let inner = self.inner.borrow();
+
+        Handler {
+            inner: RefCell::new(HandlerInner {
+                lint_overrides: inner.lint_overrides.clone(),
+                warnings_as_errors: inner.warnings_as_errors,
+                error_limit: inner
+                    .error_limit
+                    .map(|l| l.saturating_sub(inner.errors.len())),
+                ..HandlerInner::default()
+            }),
+        }
+    }
+
+    /// Move the diagnostics and lint levels of another `Handler` (e.g., a fork) into this one,
+    /// as if they had been emitted here after this `Handler`'s own diagnostics.
+    pub fn merge(&self, other: Handler) {
+        let other = other.inner.into_inner();
+
+        for diagnostic in other.errors {
+            self.emit_diagnostic(diagnostic);
+        }
+
+        for warn in other.warnings {
+            self.emit_warn(warn);
+        }
+
+        let mut inner = self.inner.borrow_mut();
+
+        inner.num_dropped_errors += other.num_dropped_errors;
+
+        for o in other.lint_overrides {
+            if !inner
+                .lint_overrides
+                .iter()
+                .any(|x| x.span == o.span && x.lint == o.lint && x.level == o.level)
+            {
+                inner.lint_overrides.push(o);
+            }
+        }
+    }
+
+    /// Return the errors and the warnings (see `errors()` and `warnings()`), sorted by file
+    /// and offset (e.g., to render them).
+    pub fn diagnostics(&self) -> (Vec<Diagnostic>, Vec<Diagnostic>) {
+        let (mut errors, mut warnings) = (self.errors(), self.warnings());
+
+        // stable, so diagnostics at the same offset keep the order that they were emitted in
+        let by_position = |d: &Diagnostic| (d.span().source_id(), d.span().start());
+
+        errors.sort_by_key(by_position);
+        warnings.sort_by_key(by_position);
+
+        (errors, warnings)
+    }
+
+    /// Take the errors and the warnings out of the `Handler`, sorted as in `diagnostics()`.
+    pub fn get_inner(self) -> (Vec<Diagnostic>, Vec<Diagnostic>) {
+        self.diagnostics()
+    }
+}
+
+impl HandlerInner {
+    // return the level of a warning's lint at its span, given the lint levels set so far
+    fn warning_level(&self, warn: &CompilerWarning) -> LintLevel {
+        lint::lint_level(warn.warning_kind.lint(), warn.span, &self.lint_overrides)
+    }
+
+    // check whether warnings at a lint level are reported as errors
+    fn is_error_level(&self, level: LintLevel) -> bool {
+        match level {
+            LintLevel::Allow => false,
+            LintLevel::Warn => self.warnings_as_errors,
+            LintLevel::Deny | LintLevel::Forbid => true,
+        }
+    }
+
+    // return the diagnostics of the warnings that are reported as errors (`as_errors`),
+    // or of those that are reported as warnings
+    fn warning_diagnostics(&self, as_errors: bool) -> impl Iterator<Item = Diagnostic> + '_ {
+        self.warnings.iter().filter_map(move |warn| {
+            let lint = warn.warning_kind.lint();
+            let level = self.warning_level(warn);
+
+            if level == LintLevel::Allow || self.is_error_level(level) != as_errors {
+                return None;
+            }
+
+            let diagnostic = Diagnostic::from(warn.clone());
+
+            let diagnostic = match level {
+                LintLevel::Warn if self.warnings_as_errors => Diagnostic {
+                    severity: Severity::Error,
+                    ..diagnostic.with_note("warnings are treated as errors")
+                },
+                LintLevel::Warn if level == lint.default_level => {
+                    diagnostic.with_note(&format!("`#[warn({})]` on by default", lint.name))
+                }
+                LintLevel::Allow | LintLevel::Warn => diagnostic,
+                LintLevel::Deny | LintLevel::Forbid => Diagnostic {
+                    severity: Severity::Error,
+                    ..diagnostic.with_note(&format!(
+                        "the lint level of `{}` is set to `{}`",
+                        lint.name, level
+                    ))
+                },
+            };
+
+            Some(diagnostic)
+        })
+    }
+}
+
+/// Handle to a `Handler` that several owners log to (e.g., a lexer and the parser that pulls
+/// tokens from it). Unlike a `Handler`, its clones share the same diagnostics.
+#[derive(Default, Debug, Clone)]
+pub struct SharedHandler(Rc<Handler>);
+
+impl SharedHandler {
+    /// Return the `Handler`, or a copy of it if other clones of this handle are still around.
+    pub fn into_inner(self) -> Handler {
+        Rc::try_unwrap(self.0).unwrap_or_else(|rc| (*rc).clone())
+    }
+}
+
+impl Deref for SharedHandler {
+    type Target = Handler;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Handler> for SharedHandler {
+    fn from(handler: Handler) -> Self {
+        Self(Rc::new(handler))
+    }
+}
+
+// what two diagnostics must have in common to say the same thing about the same span
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DiagnosticKey {
+    severity: Severity,
+    code: Option<String>,
+    lint: Option<&'static str>,
+    message: String,
+    span: Span,
+}
+
+impl DiagnosticKey {
+    fn new(diagnostic: &Diagnostic) -> Self {
+        Self {
+            severity: diagnostic.severity,
+            code: diagnostic.code.clone(),
+            lint: diagnostic.lint.map(|l| l.name),
+            message: diagnostic.message.clone(),
+            span: diagnostic.span(),
+        }
     }
 }
 
@@ -44,3 +318,194 @@ impl ErrorEmitted {
         Self { _phantom: () }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use feo_types::{source_map::SourceMap, span::Position};
+
+    use crate::{
+        lex_error::{LexError, LexErrorKind},
+        warning::WarningKind,
+    };
+
+    use super::*;
+
+    #[test]
+    fn lint_levels() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("handler_lints.feo", &" ".repeat(100));
+
+        let warning = |name: &str, start: usize| {
+            CompilerWarning::new(
+                WarningKind::UnnecessaryRawIdentifier {
+                    name: name.to_string(),
+                },
+                Span::new(src_id, start, start + 1),
+            )
+        };
+        let lint = &lint::UNNECESSARY_RAW_IDENTIFIER;
+
+        // a `forbid` cannot be lowered inside it, but other levels can be
+        let handler = Handler::default();
+
+        handler.set_lint_level(Span::new(src_id, 0, 40), lint, LintLevel::Deny);
+        handler.set_lint_level(Span::new(src_id, 10, 20), lint, LintLevel::Allow);
+        handler.set_lint_level(Span::new(src_id, 50, 80), lint, LintLevel::Forbid);
+        handler.set_lint_level(Span::new(src_id, 60, 70), lint, LintLevel::Allow);
+
+        handler.emit_warn(warning("a", 5));
+        handler.emit_warn(warning("b", 15));
+        handler.emit_warn(warning("c", 65));
+        handler.emit_warn(warning("d", 85));
+        handler.emit_warn(warning("d", 85));
+
+        let (errors, warnings) = handler.clone().get_inner();
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| e.message.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "unnecessary raw identifier: `a` is not a keyword",
+                "unnecessary raw identifier: `c` is not a keyword"
+            ]
+        );
+        assert_eq!(
+            errors[1].notes,
+            ["the lint level of `unnecessary_raw_identifier` is set to `forbid`"]
+        );
+
+        // lints are named rather than numbered
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, None);
+        assert_eq!(warnings[0].lint, Some(lint));
+        assert_eq!(
+            warnings[0].notes,
+            ["`#[warn(unnecessary_raw_identifier)]` on by default"]
+        );
+
+        // warnings as errors, except for allowed lints
+        handler.set_warnings_as_errors(true);
+
+        let (errors, warnings) = handler.get_inner();
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|e| e.is_error()));
+        assert_eq!(errors[2].notes, ["warnings are treated as errors"]);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn error_policies() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("policies.feo", "let a = 1 € 2;");
+        let other_id = source_map.add_file("policies_other.feo", "let a = 1 € 2;");
+
+        let error_at = |src_id, pos: usize| {
+            CompilerError::Lex(LexError {
+                error_kind: LexErrorKind::InvalidChar('€'),
+                position: Position::new(src_id, pos),
+            })
+        };
+
+        // identical diagnostics at the same span are only kept once
+        let handler = Handler::default();
+        handler.emit_err(error_at(src_id, 10));
+        handler.emit_err(error_at(src_id, 10));
+        handler.emit_err(error_at(src_id, 2));
+
+        assert_eq!(handler.errors().len(), 2);
+        assert_eq!(handler.num_dropped_errors(), 0);
+
+        // errors after the limit are counted, but not kept
+        handler.set_error_limit(Some(2));
+        handler.emit_err(error_at(src_id, 4));
+        handler.emit_err(error_at(src_id, 5));
+
+        assert!(handler.error_limit_reached());
+        assert_eq!(handler.errors().len(), 2);
+        assert_eq!(handler.num_dropped_errors(), 2);
+
+        // a fork starts empty, and is separate from its handler until it is merged back
+        let fork = handler.fork();
+        fork.set_error_limit(None);
+        fork.emit_err(error_at(other_id, 0));
+        fork.emit_err(error_at(src_id, 6));
+        fork.emit_err(error_at(src_id, 10));
+
+        assert!(fork.has_errors());
+        assert_eq!(fork.errors().len(), 3);
+        assert_eq!(handler.errors().len(), 2);
+
+        // errors are kept in the order that they were emitted in, and sorted by file and
+        // offset when they are taken out to be rendered
+        handler.set_error_limit(None);
+        handler.merge(fork);
+
+        let positions = |errors: Vec<Diagnostic>| {
+            errors
+                .iter()
+                .map(|e| (e.span().source_id(), e.span().start()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            positions(handler.errors()),
+            [(src_id, 10), (src_id, 2), (other_id, 0), (src_id, 6)]
+        );
+        assert_eq!(
+            positions(handler.diagnostics().0),
+            [(src_id, 2), (src_id, 6), (src_id, 10), (other_id, 0)]
+        );
+        assert_eq!(handler.num_dropped_errors(), 2);
+        assert!(!Handler::default().has_errors());
+
+        // a clone is a separate copy, but the clones of a shared handle log to the same place
+        handler.clone().emit_err(error_at(src_id, 1));
+
+        assert_eq!(handler.errors().len(), 4);
+
+        let shared = SharedHandler::from(handler);
+        shared.clone().emit_err(error_at(src_id, 1));
+
+        assert_eq!(shared.errors().len(), 5);
+        assert_eq!(shared.into_inner().errors().len(), 5);
+    }
+
+    #[test]
+    fn merge_forks_from_threads() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("threads.feo", "€€€€");
+
+        let handler = Handler::default();
+        handler.set_error_limit(Some(3));
+
+        // forks are owned by the threads that log to them, and merged back by value
+        let threads = (0..4)
+            .map(|i| {
+                let fork = handler.fork();
+
+                std::thread::spawn(move || {
+                    fork.emit_err(CompilerError::Lex(LexError {
+                        error_kind: LexErrorKind::InvalidChar('€'),
+                        position: Position::new(src_id, i * 3),
+                    }));
+
+                    fork
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            handler.merge(thread.join().expect("thread panicked"));
+        }
+
+        assert!(handler.has_errors());
+        assert_eq!(handler.errors().len(), 3);
+        assert_eq!(handler.num_dropped_errors(), 1);
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+
+use feo_types::{source_map::SourceMap, span::Span};
+
+use crate::{
+    diagnostic::{Diagnostic, Label},
+    handler::Handler,
+    suggestion::Suggestion,
+};
+
+/// Minimal JSON value, for writing diagnostics in machine-readable formats
+#[derive(Debug, Clone)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(usize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub(crate) fn string(s: &str) -> Json {
+        Json::String(s.to_string())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, |v| v.into())
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::string(value)
+    }
+}
+
+impl From<&String> for Json {
+    fn from(value: &String) -> Self {
+        Json::string(value)
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) => write!(f, "{}", n),
+            Json::String(s) => {
+                write!(f, "\"")?;
+
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                        c => write!(f, "{}", c)?,
+                    }
+                }
+
+                write!(f, "\"")
+            }
+            Json::Array(values) => {
+                write!(f, "[")?;
+
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", v)?;
+                }
+
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+
+                for (i, (k, v)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}:{}", Json::string(k), v)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Return the diagnostics in the `Handler` (errors, then warnings) as line-delimited JSON,
+/// i.e., one JSON object per diagnostic (see `to_json()`), each on its own line.
+pub fn to_json_lines(source_map: &SourceMap, handler: &Handler) -> String {
+    let (errors, warnings) = handler.diagnostics();
+    let diagnostics = errors
+        .into_iter()
+        .chain(warnings)
+        .collect::<Vec<Diagnostic>>();
+
+    diagnostics
+        .iter()
+        .map(|d| format!("{}\n", to_json(source_map, d)))
+        .collect::<String>()
+}
+
+/// Return a diagnostic as a single-line JSON object, e.g.:
+/// ```json
+/// {"code":"E0102","lint":null,"severity":"error","message":"invalid char literal",
+///  "file":"main.feo","span":{"start":10,"end":10,"start_line":1,"start_col":11,
+///  "end_line":1,"end_col":11},"labels":[{"primary":true,"message":"","file":"main.feo",
+///  "span":{..}}],"notes":[],"help":null,"suggestions":[{"replacement":";",
+///  "applicability":"machine-applicable","file":"main.feo","span":{..}}]}
+/// ```
+/// Errors have a `code`, while warnings have the name of the `lint` that reported them.
+/// Files and lines are looked up in `source_map`.
+/// Byte offsets are zero-based, lines and columns (counted in chars) are one-based,
+/// and the end of a span is exclusive.
+pub fn to_json(source_map: &SourceMap, diagnostic: &Diagnostic) -> String {
+    let span = diagnostic.span();
+
+    let labels = std::iter::once((&diagnostic.primary, true))
+        .chain(diagnostic.secondary.iter().map(|l| (l, false)))
+        .map(|(label, is_primary)| label_json(source_map, label, is_primary))
+        .collect::<Vec<Json>>();
+
+    Json::Object(vec![
+        ("code", diagnostic.code.as_ref().into()),
+        ("lint", diagnostic.lint.map(|l| l.name).into()),
+        ("severity", Json::String(diagnostic.severity.to_string())),
+        ("message", Json::string(&diagnostic.message)),
+        ("file", file_name(source_map, span).into()),
+        ("span", span_json(source_map, span)),
+        ("labels", Json::Array(labels)),
+        (
+            "notes",
+            Json::Array(diagnostic.notes.iter().map(Json::from).collect()),
+        ),
+        ("help", diagnostic.help.as_ref().into()),
+        (
+            "suggestions",
+            Json::Array(
+                diagnostic
+                    .suggestions
+                    .iter()
+                    .map(|s| suggestion_json(source_map, s))
+                    .collect(),
+            ),
+        ),
+    ])
+    .to_string()
+}
+
+fn label_json(source_map: &SourceMap, label: &Label, is_primary: bool) -> Json {
+    Json::Object(vec![
+        ("primary", Json::Bool(is_primary)),
+        ("message", Json::string(&label.message)),
+        ("file", file_name(source_map, label.span).into()),
+        ("span", span_json(source_map, label.span)),
+    ])
+}
+
+fn suggestion_json(source_map: &SourceMap, suggestion: &Suggestion) -> Json {
+    Json::Object(vec![
+        ("replacement", Json::string(&suggestion.replacement)),
+        (
+            "applicability",
+            Json::string(suggestion.applicability.as_str()),
+        ),
+        ("file", file_name(source_map, suggestion.span).into()),
+        ("span", span_json(source_map, suggestion.span)),
+    ])
+}
+
+// byte offsets and line / column ranges of a span
+// (`null` lines and columns for a span that does not point into a file)
+fn span_json(source_map: &SourceMap, span: Span) -> Json {
+    let line_col = |pos: usize| span.source(source_map).map(|f| f.line_col(pos));
+
+    let start = line_col(span.start());
+    let end = line_col(span.end());
+
+    Json::Object(vec![
+        ("start", Json::Number(span.start())),
+        ("end", Json::Number(span.end())),
+        ("start_line", start.map(|(l, _)| Json::Number(l)).into()),
+        ("start_col", start.map(|(_, c)| Json::Number(c)).into()),
+        ("end_line", end.map(|(l, _)| Json::Number(l)).into()),
+        ("end_col", end.map(|(_, c)| Json::Number(c)).into()),
+    ])
+}
+
+pub(crate) fn file_name(source_map: &SourceMap, span: Span) -> Option<&str> {
+    span.source(source_map).map(|f| f.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_types::span::Position;
+
+    use crate::{
+        error::CompilerError,
+        lex_error::{LexError, LexErrorKind},
+    };
+
+    use super::*;
+
+    #[test]
+    fn json_lines() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("output.feo", "foo(a[0);");
+
+        let handler = Handler::default();
+
+        handler.emit_err(CompilerError::Lex(LexError {
+            error_kind: LexErrorKind::MismatchedDelimiters {
+                open: '[',
+                open_position: Position::new(src_id, 5),
+                close: ')',
+            },
+            position: Position::new(src_id, 7),
+        }));
+
+        assert_eq!(
+            to_json_lines(&source_map, &handler),
+            concat!(
+                r#"{"code":"E0114","lint":null,"severity":"error","#,
+                r#""message":"mismatched delimiters: `[` closed by `)`","#,
+                r#""file":"output.feo","span":{"start":7,"end":8,"start_line":1,"start_col":8,"#,
+                r#""end_line":1,"end_col":9},"labels":[{"primary":true,"#,
+                r#""message":"`)` does not match `[`","file":"output.feo","#,
+                r#""span":{"start":7,"end":8,"start_line":1,"start_col":8,"end_line":1,"end_col":9}},"#,
+                r#"{"primary":false,"message":"`[` opened here","file":"output.feo","#,
+                r#""span":{"start":5,"end":6,"start_line":1,"start_col":6,"end_line":1,"end_col":7}}],"#,
+                r#""notes":[],"help":null,"suggestions":[]}"#,
+                "\n"
+            )
+        );
+    }
+}
@@ -12,10 +12,18 @@ pub enum LexErrorKind {
     ExpectedClosingDoubleQuote,
     InvalidEscapeSequence,
     ExpectedEscapeSequence,
+    NonAsciiByteLiteral,
     UnclosedBlockComment,
     UnclosedDelimiters,
     UnexpectedCloseDelimiter,
-    MismatchedDelimiters,
+    InvalidNumericSuffix,
+    InvalidRawIdentifier,
+
+    MismatchedDelimiters {
+        open: char,
+        open_position: Position,
+        close: char,
+    },
 
     InvalidChar(char),
 
@@ -23,6 +31,31 @@ pub enum LexErrorKind {
     UnknownError,
 }
 
+impl LexErrorKind {
+    /// Return the stable code of the error (e.g., to look up its explanation).
+    /// Codes are never reused or renumbered, even if the error kind is removed.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LexErrorKind::EmptyCharLiteral => "E0101",
+            LexErrorKind::InvalidCharLiteral => "E0102",
+            LexErrorKind::ExpectedCharLiteral => "E0103",
+            LexErrorKind::ExpectedClosingSingleQuote => "E0104",
+            LexErrorKind::ExpectedClosingDoubleQuote => "E0105",
+            LexErrorKind::InvalidEscapeSequence => "E0106",
+            LexErrorKind::ExpectedEscapeSequence => "E0107",
+            LexErrorKind::NonAsciiByteLiteral => "E0108",
+            LexErrorKind::UnclosedBlockComment => "E0109",
+            LexErrorKind::UnclosedDelimiters => "E0110",
+            LexErrorKind::UnexpectedCloseDelimiter => "E0111",
+            LexErrorKind::InvalidNumericSuffix => "E0112",
+            LexErrorKind::InvalidRawIdentifier => "E0113",
+            LexErrorKind::MismatchedDelimiters { .. } => "E0114",
+            LexErrorKind::InvalidChar(_) => "E0115",
+            LexErrorKind::UnknownError => "E0100",
+        }
+    }
+}
+
 impl fmt::Display for LexErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -33,10 +66,19 @@ impl fmt::Display for LexErrorKind {
             LexErrorKind::ExpectedClosingDoubleQuote => write!(f, "expected closing double quote"),
             LexErrorKind::InvalidEscapeSequence => write!(f, "invalid escape sequence"),
             LexErrorKind::ExpectedEscapeSequence => write!(f, "expected escape sequence"),
+            LexErrorKind::NonAsciiByteLiteral => {
+                write!(f, "non-ASCII character in byte literal")
+            }
             LexErrorKind::UnclosedBlockComment => write!(f, "unclosed block comment"),
             LexErrorKind::UnclosedDelimiters => write!(f, "unclosed delimiters"),
             LexErrorKind::UnexpectedCloseDelimiter => write!(f, "unexpected close delimiter"),
-            LexErrorKind::MismatchedDelimiters => write!(f, "mismatched delimiters"),
+            LexErrorKind::InvalidNumericSuffix => write!(f, "invalid numeric literal suffix"),
+            LexErrorKind::InvalidRawIdentifier => {
+                write!(f, "`self`, `Self` and `super` cannot be raw identifiers")
+            }
+            LexErrorKind::MismatchedDelimiters { open, close, .. } => {
+                write!(f, "mismatched delimiters: `{}` closed by `{}`", open, close)
+            }
             LexErrorKind::InvalidChar(c) => write!(f, "invalid char (`{}`)", c),
             LexErrorKind::UnknownError => write!(f, "unknown error"),
         }
@@ -55,12 +97,6 @@ impl Error for LexError {}
 
 impl fmt::Display for LexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}:{}",
-            self.error_kind,
-            self.position.line_col().0,
-            self.position.line_col().1
-        )
+        write!(f, "{}", self.error_kind)
     }
 }
@@ -1,6 +1,13 @@
+pub mod diagnostic;
 pub mod error;
+pub mod explanation;
 pub mod handler;
+pub mod json;
 pub mod lex_error;
+pub mod lint;
 pub mod parser_error;
+pub mod renderer;
+pub mod sarif;
+pub mod suggestion;
 pub mod type_error;
 pub mod warning;
@@ -0,0 +1,167 @@
+use std::{fmt, str::FromStr};
+
+use feo_types::span::Span;
+
+/// What the compiler does when a lint is triggered
+/// (ordered from least to most severe)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// Ignore the lint
+    Allow,
+    /// Report a warning
+    Warn,
+    /// Report an error
+    Deny,
+    /// Report an error, and do not let inner scopes change the level
+    Forbid,
+}
+
+impl LintLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+            LintLevel::Forbid => "forbid",
+        }
+    }
+}
+
+impl FromStr for LintLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            "forbid" => Ok(LintLevel::Forbid),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Check that the compiler can run on the source code, which reports a warning
+/// (by default) when it finds something suspicious
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// Name used to refer to the lint in attributes (e.g., `#[allow(unnecessary_raw_identifier)]`)
+    pub name: &'static str,
+    pub default_level: LintLevel,
+    pub description: &'static str,
+}
+
+pub const UNNECESSARY_RAW_IDENTIFIER: Lint = Lint {
+    name: "unnecessary_raw_identifier",
+    default_level: LintLevel::Warn,
+    description: "detects raw identifiers (`r#name`) whose name is not a keyword",
+};
+
+pub const UNKNOWN_LINT: Lint = Lint {
+    name: "unknown_lint",
+    default_level: LintLevel::Warn,
+    description: "detects lint attributes with a lint name that does not exist",
+};
+
+/// All the lints that the compiler knows about
+pub const LINTS: &[&Lint] = &[&UNNECESSARY_RAW_IDENTIFIER, &UNKNOWN_LINT];
+
+/// Return the lint with the given name, or `None` if there is no such lint.
+pub fn find_lint(name: &str) -> Option<&'static Lint> {
+    LINTS.iter().find(|l| l.name == name).copied()
+}
+
+/// Level of a lint in the source code covered by a span
+/// (e.g., an item with a `#[deny(..)]` attribute)
+#[derive(Debug, Clone)]
+pub struct LintOverride {
+    pub span: Span,
+    pub lint: &'static Lint,
+    pub level: LintLevel,
+}
+
+/// Return the level of a lint at a span, i.e., the level set by the innermost override that
+/// covers the span, or the lint's default level if there is none.
+/// A `forbid` override cannot be changed by the overrides inside it.
+pub fn lint_level(lint: &Lint, span: Span, overrides: &[LintOverride]) -> LintLevel {
+    let mut covering = overrides
+        .iter()
+        .filter(|o| {
+            o.lint == lint
+                && o.span.source_id() == span.source_id()
+                && o.span.start() <= span.start()
+                && span.end() <= o.span.end()
+        })
+        .collect::<Vec<&LintOverride>>();
+
+    // outermost first (overrides with the same span keep the order that they were set in)
+    covering.sort_by_key(|o| (o.span.start(), std::cmp::Reverse(o.span.end())));
+
+    if let Some(forbid) = covering.iter().find(|o| o.level == LintLevel::Forbid) {
+        return forbid.level;
+    }
+
+    covering.last().map_or(lint.default_level, |o| o.level)
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_types::source_map::SourceMap;
+
+    use super::*;
+
+    #[test]
+    fn lint_level_overrides() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lint_levels.feo", &" ".repeat(100));
+
+        let lint = &UNNECESSARY_RAW_IDENTIFIER;
+        let span = |start: usize, end: usize| Span::new(src_id, start, end);
+        let set = |start: usize, end: usize, level: LintLevel| LintOverride {
+            span: span(start, end),
+            lint,
+            level,
+        };
+
+        let overrides = [
+            set(0, 40, LintLevel::Deny),
+            set(10, 20, LintLevel::Allow),
+            set(50, 80, LintLevel::Forbid),
+            set(60, 70, LintLevel::Allow),
+        ];
+
+        // the innermost override wins, unless an outer one forbids changing it
+        assert_eq!(lint_level(lint, span(5, 6), &overrides), LintLevel::Deny);
+        assert_eq!(lint_level(lint, span(15, 16), &overrides), LintLevel::Allow);
+        assert_eq!(
+            lint_level(lint, span(65, 66), &overrides),
+            LintLevel::Forbid
+        );
+        assert_eq!(lint_level(lint, span(85, 86), &overrides), LintLevel::Warn);
+
+        // overrides of other lints, or that only cover part of the span, do not apply
+        assert_eq!(
+            lint_level(&UNKNOWN_LINT, span(5, 6), &overrides),
+            LintLevel::Warn
+        );
+        assert_eq!(lint_level(lint, span(35, 45), &overrides), LintLevel::Warn);
+    }
+
+    #[test]
+    fn find_lints_by_name() {
+        assert_eq!(
+            find_lint("unnecessary_raw_identifier"),
+            Some(&UNNECESSARY_RAW_IDENTIFIER)
+        );
+        assert_eq!(find_lint("no_such_lint"), None);
+        assert_eq!("forbid".parse::<LintLevel>(), Ok(LintLevel::Forbid));
+        assert!(LintLevel::Warn < LintLevel::Deny);
+    }
+}
@@ -6,6 +6,7 @@ use feo_types::{keyword::KeywordKind, punctuation::PuncKind, span::Position};
 #[derive(Default, Debug, Clone, PartialEq)]
 pub enum ParserErrorKind {
     ParseCharError,
+    ParseByteError,
     ParseBoolError,
     ParseIntError,
     ParseUIntError,
@@ -37,14 +38,55 @@ pub enum ParserErrorKind {
 
     TokenNotFound,
 
+    /// Keyword from another language (e.g., Rust's `fn`) that has a Feo equivalent
+    ForeignKeyword {
+        found: String,
+        replacement: String,
+    },
+
+    /// Word that cannot start an item or statement, with the closest keyword or declared
+    /// identifier, if any
+    UnknownWord {
+        found: String,
+        suggestion: Option<String>,
+    },
+
     #[default]
     UnknownError,
 }
 
+impl ParserErrorKind {
+    /// Return the stable code of the error (e.g., to look up its explanation).
+    /// Codes are never reused or renumbered, even if the error kind is removed.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParserErrorKind::ParseCharError => "E0201",
+            ParserErrorKind::ParseByteError => "E0202",
+            ParserErrorKind::ParseBoolError => "E0203",
+            ParserErrorKind::ParseIntError => "E0204",
+            ParserErrorKind::ParseUIntError => "E0205",
+            ParserErrorKind::ParseU256Error => "E0206",
+            ParserErrorKind::ParseFloatError => "E0207",
+            ParserErrorKind::ParseTypeAnnotationError => "E0208",
+            ParserErrorKind::CharPositionNotFound => "E0209",
+            ParserErrorKind::MissingDelimiter { .. } => "E0210",
+            ParserErrorKind::InvalidKeyword { .. } => "E0211",
+            ParserErrorKind::InvalidPunctuation { .. } => "E0212",
+            ParserErrorKind::InvalidToken { .. } => "E0213",
+            ParserErrorKind::UnexpectedToken { .. } => "E0214",
+            ParserErrorKind::TokenNotFound => "E0215",
+            ParserErrorKind::ForeignKeyword { .. } => "E0216",
+            ParserErrorKind::UnknownWord { .. } => "E0217",
+            ParserErrorKind::UnknownError => "E0200",
+        }
+    }
+}
+
 impl fmt::Display for ParserErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ParserErrorKind::ParseCharError => write!(f, "unable to parse char"),
+            ParserErrorKind::ParseByteError => write!(f, "unable to parse byte"),
             ParserErrorKind::ParseBoolError => write!(f, "unable to parse bool"),
             ParserErrorKind::ParseIntError => write!(f, "unable to parse int"),
             ParserErrorKind::ParseUIntError => write!(f, "unable to parse uint"),
@@ -80,6 +122,10 @@ impl fmt::Display for ParserErrorKind {
                 expected, found,
             ),
             ParserErrorKind::TokenNotFound => write!(f, "token not found"),
+            ParserErrorKind::ForeignKeyword { found, .. } => {
+                write!(f, "`{}` is not a keyword in Feo", found)
+            }
+            ParserErrorKind::UnknownWord { found, .. } => write!(f, "unknown word: `{}`", found),
             ParserErrorKind::UnknownError => write!(f, "unknown error"),
         }
     }
@@ -97,12 +143,6 @@ impl Error for ParserError {}
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}:{}",
-            self.error_kind,
-            self.position.line_col().0,
-            self.position.line_col().1
-        )
+        write!(f, "{}", self.error_kind)
     }
 }
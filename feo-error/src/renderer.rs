@@ -0,0 +1,420 @@
+use feo_types::{
+    source_map::{SourceFile, SourceId, SourceMap},
+    span::Span,
+};
+
+use crate::{
+    diagnostic::{Diagnostic, Label, Severity},
+    handler::Handler,
+    suggestion::Suggestion,
+};
+
+/// Number of columns that a tab is expanded to in a rendered source line
+const TAB_WIDTH: usize = 4;
+
+/// Whether rendered diagnostics are styled with ANSI escape codes (e.g., for a terminal),
+/// or left as plain text (e.g., for logs)
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColorMode {
+    #[default]
+    Plain,
+    Ansi,
+}
+
+/// Renders diagnostics as human-readable text, with the source lines that they refer to
+/// and the labelled spans underlined, e.g.:
+/// ```text
+/// error[E0114]: mismatched delimiters: `[` closed by `)`
+///  --> main.feo:1:8
+///   |
+/// 1 | foo(a[0);
+///   |      - `[` opened here
+///   |        ^ `)` does not match `[`
+/// ```
+/// The source lines are looked up in the `SourceMap` that the diagnostics' spans point into.
+#[derive(Debug, Copy, Clone)]
+pub struct Renderer<'a> {
+    source_map: &'a SourceMap,
+    color_mode: ColorMode,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(source_map: &'a SourceMap, color_mode: ColorMode) -> Self {
+        Self {
+            source_map,
+            color_mode,
+        }
+    }
+
+    /// Render all the diagnostics in the `Handler` (errors, then warnings), separated by
+    /// blank lines.
+    pub fn render_handler(&self, handler: &Handler) -> String {
+        let (errors, warnings) = handler.diagnostics();
+
+        errors
+            .iter()
+            .chain(warnings.iter())
+            .map(|d| self.render(d))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut output = String::new();
+
+        let severity_style = match diagnostic.severity {
+            Severity::Error => "1;31",
+            Severity::Warning => "1;33",
+            Severity::Note => "1;32",
+        };
+
+        let severity = match &diagnostic.code {
+            Some(code) => format!("{}[{}]", diagnostic.severity, code),
+            None => diagnostic.severity.to_string(),
+        };
+
+        output.push_str(&self.paint(&severity, severity_style));
+        output.push_str(&self.paint(&format!(": {}", diagnostic.message), "1"));
+        output.push('\n');
+
+        // labels are grouped by file, starting with the file of the primary label
+        let mut labels: Vec<(&Label, bool)> = vec![(&diagnostic.primary, true)];
+        labels.extend(diagnostic.secondary.iter().map(|l| (l, false)));
+
+        let mut src_ids: Vec<SourceId> = Vec::new();
+
+        for (label, _) in labels.iter() {
+            if !src_ids.contains(&label.span.source_id()) {
+                src_ids.push(label.span.source_id());
+            }
+        }
+
+        // spans that do not point into a file (e.g., `Span::default()`) have no snippet
+        let files = src_ids
+            .iter()
+            .filter_map(|id| self.source_map.get(*id))
+            .collect::<Vec<&SourceFile>>();
+
+        // width of the line number gutter
+        let gutter_width = labels
+            .iter()
+            .filter_map(|(l, _)| {
+                l.span
+                    .source(self.source_map)
+                    .map(|f| f.line_col(l.span.end()).0)
+            })
+            .max()
+            .map_or(0, |line| line.to_string().len());
+
+        let gutter = " ".repeat(gutter_width);
+
+        for (i, file) in files.iter().enumerate() {
+            let file_labels = labels
+                .iter()
+                .filter(|(l, _)| l.span.source_id() == file.id())
+                .copied()
+                .collect::<Vec<(&Label, bool)>>();
+
+            let (line, col) = file.line_col(file_labels[0].0.span.start());
+            let arrow = if i == 0 { "-->" } else { ":::" };
+
+            output.push_str(&format!(
+                "{}{} {}:{}:{}\n",
+                gutter,
+                self.paint(arrow, "1;34"),
+                file.name(),
+                line,
+                col
+            ));
+
+            output.push_str(&format!("{} {}\n", gutter, self.paint("|", "1;34")));
+
+            self.render_snippet(
+                &mut output,
+                file,
+                &file_labels,
+                gutter_width,
+                severity_style,
+            );
+        }
+
+        for note in diagnostic.notes.iter() {
+            output.push_str(&format!(
+                "{} {} note: {}\n",
+                gutter,
+                self.paint("=", "1;34"),
+                note
+            ));
+        }
+
+        if let Some(help) = &diagnostic.help {
+            output.push_str(&format!(
+                "{} {} help: {}\n",
+                gutter,
+                self.paint("=", "1;34"),
+                help
+            ));
+        }
+
+        for suggestion in diagnostic.suggestions.iter() {
+            output.push_str(&format!(
+                "{} {} suggestion: {}\n",
+                gutter,
+                self.paint("=", "1;34"),
+                describe_suggestion(self.source_map, suggestion)
+            ));
+        }
+
+        output
+    }
+
+    // render the lines of a file that are covered by the labels, with each label underlined
+    // (`^` for the primary label, `-` for secondary labels) and its message after the
+    // underline on its last line
+    fn render_snippet(
+        &self,
+        output: &mut String,
+        file: &SourceFile,
+        labels: &[(&Label, bool)],
+        gutter_width: usize,
+        severity_style: &str,
+    ) {
+        let mut lines = labels
+            .iter()
+            .flat_map(|(l, _)| {
+                let (start_line, _) = file.line_col(l.span.start());
+                let (end_line, _) = file.line_col(l.span.end());
+                start_line..=end_line
+            })
+            .collect::<Vec<usize>>();
+
+        lines.sort();
+        lines.dedup();
+
+        let mut prev_line: Option<usize> = None;
+
+        for line in lines {
+            let text = file.line_text(line).unwrap_or("");
+
+            let mut underlines = labels
+                .iter()
+                .filter_map(|(label, is_primary)| {
+                    underline(file, label.span, line, text)
+                        .map(|(start, end, is_last)| (start, end, is_last, *label, *is_primary))
+                })
+                .collect::<Vec<_>>();
+
+            underlines.sort_by_key(|(start, ..)| *start);
+
+            // skip the last line of a multiline span if it ends at the start of the line
+            if underlines.is_empty() {
+                continue;
+            }
+
+            if prev_line.is_some_and(|p| line > p + 1) {
+                output.push_str(&format!("{}\n", self.paint("...", "1;34")));
+            }
+
+            prev_line = Some(line);
+
+            output.push_str(&self.paint(&format!("{:>1$} |", line, gutter_width), "1;34"));
+
+            if !text.is_empty() {
+                output.push(' ');
+                output.push_str(&expand_tabs(text));
+            }
+
+            output.push('\n');
+
+            for (start, end, is_last, label, is_primary) in underlines {
+                let (mark, style) = if is_primary {
+                    ('^', severity_style)
+                } else {
+                    ('-', "1;34")
+                };
+
+                let mut marks = mark.to_string().repeat(end - start);
+
+                if is_last && !label.message.is_empty() {
+                    marks.push(' ');
+                    marks.push_str(&label.message);
+                }
+
+                output.push_str(&format!(
+                    "{} {} {}{}\n",
+                    " ".repeat(gutter_width),
+                    self.paint("|", "1;34"),
+                    " ".repeat(start),
+                    self.paint(&marks, style)
+                ));
+            }
+        }
+    }
+
+    fn paint(&self, text: &str, style: &str) -> String {
+        match self.color_mode {
+            ColorMode::Plain => text.to_string(),
+            ColorMode::Ansi => format!("\x1b[{}m{}\x1b[0m", style, text),
+        }
+    }
+}
+
+// return the display columns (start inclusive, end exclusive) of the part of a span that is
+// on a line, and whether it is the last line of the span
+// return `None` if the span is not on the line
+fn underline(
+    file: &SourceFile,
+    span: Span,
+    line: usize,
+    text: &str,
+) -> Option<(usize, usize, bool)> {
+    let (start_line, start_col) = file.line_col(span.start());
+    let (end_line, end_col) = file.line_col(span.end());
+
+    if line < start_line
+        || line > end_line
+        || (line > start_line && line == end_line && end_col == 1)
+    {
+        return None;
+    }
+
+    let line_len = text.chars().count();
+
+    let start = if line == start_line { start_col - 1 } else { 0 };
+
+    let end = if line == end_line {
+        end_col - 1
+    } else {
+        line_len
+    };
+
+    let is_last = line == end_line || (line + 1 == end_line && end_col == 1);
+
+    let start_width = display_width(text, start);
+    let end_width = display_width(text, end).max(start_width + 1);
+
+    Some((start_width, end_width, is_last))
+}
+
+// width of the first `num_chars` chars of a line, after expanding tabs
+fn display_width(text: &str, num_chars: usize) -> usize {
+    let width = text
+        .chars()
+        .take(num_chars)
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum::<usize>();
+
+    // columns past the end of the line (e.g., a span at the end of the input)
+    width + num_chars.saturating_sub(text.chars().count())
+}
+
+// e.g., "insert `;` at 3:10", "remove `mut` at 1:5" or "replace `fn` with `func` at 1:1"
+fn describe_suggestion(source_map: &SourceMap, suggestion: &Suggestion) -> String {
+    let span = suggestion.span;
+
+    let edit = match (span.is_empty(), suggestion.replacement.is_empty()) {
+        (true, _) => format!("insert `{}`", suggestion.replacement),
+        (false, true) => format!("remove `{}`", span.as_str(source_map)),
+        (false, false) => format!(
+            "replace `{}` with `{}`",
+            span.as_str(source_map),
+            suggestion.replacement
+        ),
+    };
+
+    match span.source(source_map) {
+        Some(file) => {
+            let (line, col) = file.line_col(span.start());
+            format!("{} at {}:{}", edit, line, col)
+        }
+        None => edit,
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_types::span::Position;
+
+    use crate::{
+        error::CompilerError,
+        lex_error::{LexError, LexErrorKind},
+        suggestion::Applicability,
+    };
+
+    use super::*;
+
+    #[test]
+    fn render_handler() {
+        let source_code = "func foo() {\n\tlet x = [1, 2);\n}\nlet c = 'ab';";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("render.feo", source_code);
+
+        let handler = Handler::default();
+
+        handler.emit_err(CompilerError::Lex(LexError {
+            error_kind: LexErrorKind::InvalidCharLiteral,
+            position: Position::new(src_id, 42),
+        }));
+        handler.emit_err(CompilerError::Lex(LexError {
+            error_kind: LexErrorKind::MismatchedDelimiters {
+                open: '[',
+                open_position: Position::new(src_id, 22),
+                close: ')',
+            },
+            position: Position::new(src_id, 27),
+        }));
+
+        let output = Renderer::new(&source_map, ColorMode::Plain).render_handler(&handler);
+
+        assert_eq!(
+            output,
+            "error[E0114]: mismatched delimiters: `[` closed by `)`
+ --> render.feo:2:15
+  |
+2 |     let x = [1, 2);
+  |             - `[` opened here
+  |                  ^ `)` does not match `[`
+
+error[E0102]: invalid char literal
+ --> render.feo:4:11
+  |
+4 | let c = 'ab';
+  |           ^
+"
+        );
+
+        let output = Renderer::new(&source_map, ColorMode::Ansi).render_handler(&handler);
+
+        assert!(output.starts_with("\x1b[1;31merror[E0114]\x1b[0m"));
+    }
+
+    #[test]
+    fn render_suggestion() {
+        let source_code = "func foo() {\n    bar(baz[0\n";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("fix.feo", source_code);
+
+        let diagnostic = Diagnostic::error("unclosed delimiters", Span::new(src_id, 11, 12))
+            .with_suggestion(Suggestion::insertion(
+                src_id,
+                source_code.len(),
+                "])}",
+                Applicability::MachineApplicable,
+            ));
+
+        let rendered = Renderer::new(&source_map, ColorMode::Plain).render(&diagnostic);
+
+        assert!(
+            rendered.ends_with("  = suggestion: insert `])}` at 3:1\n"),
+            "{rendered}"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use feo_types::{source_map::SourceMap, span::Span};
+
+use crate::{
+    diagnostic::{Diagnostic, Label, Severity},
+    explanation::explain,
+    handler::Handler,
+    json::{file_name, Json},
+    suggestion::Suggestion,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Return the diagnostics in the `Handler` (whose spans point into `source_map`) as a
+/// SARIF 2.1.0 log with a single run,
+/// e.g., for code scanning tools.
+/// Each diagnostic is a result, whose rule is its error code or the name of its lint
+/// (rules are described by the first line of the explanation of the code, or by the
+/// description of the lint), its secondary labels are related locations,
+/// and its suggestions are fixes.
+/// Columns are counted in Unicode code points.
+pub fn to_sarif(source_map: &SourceMap, handler: &Handler) -> String {
+    let (errors, warnings) = handler.diagnostics();
+    let diagnostics = errors
+        .into_iter()
+        .chain(warnings)
+        .collect::<Vec<Diagnostic>>();
+
+    let mut rule_ids: Vec<&str> = diagnostics.iter().filter_map(rule_id).collect();
+    rule_ids.sort();
+    rule_ids.dedup();
+
+    let rules = rule_ids
+        .iter()
+        .map(|id| {
+            let mut rule = vec![("id", Json::string(id))];
+
+            if let Some(description) = rule_description(&diagnostics, id) {
+                rule.push((
+                    "shortDescription",
+                    Json::Object(vec![("text", Json::string(description))]),
+                ));
+            }
+
+            Json::Object(rule)
+        })
+        .collect::<Vec<Json>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|d| result_json(source_map, d, &rule_ids))
+        .collect::<Vec<Json>>();
+
+    let run = Json::Object(vec![
+        (
+            "tool",
+            Json::Object(vec![(
+                "driver",
+                Json::Object(vec![
+                    ("name", Json::string("feo")),
+                    ("rules", Json::Array(rules)),
+                ]),
+            )]),
+        ),
+        ("columnKind", Json::string("unicodeCodePoints")),
+        ("results", Json::Array(results)),
+    ]);
+
+    Json::Object(vec![
+        ("$schema", Json::string(SARIF_SCHEMA)),
+        ("version", Json::string(SARIF_VERSION)),
+        ("runs", Json::Array(vec![run])),
+    ])
+    .to_string()
+}
+
+// the rule of a diagnostic is its error code, or the name of the lint that reported it
+fn rule_id(diagnostic: &Diagnostic) -> Option<&str> {
+    diagnostic
+        .code
+        .as_deref()
+        .or(diagnostic.lint.map(|l| l.name))
+}
+
+// error codes are described by the first line of their explanation, and lints by their
+// description
+fn rule_description(diagnostics: &[Diagnostic], id: &str) -> Option<&'static str> {
+    let diagnostic = diagnostics.iter().find(|d| rule_id(d) == Some(id))?;
+
+    match (&diagnostic.code, diagnostic.lint) {
+        (Some(code), _) => explain(code).and_then(|e| e.lines().next()),
+        (None, Some(lint)) => Some(lint.description),
+        (None, None) => None,
+    }
+}
+
+fn result_json(source_map: &SourceMap, diagnostic: &Diagnostic, rule_ids: &[&str]) -> Json {
+    let level = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    };
+
+    // notes and help text are appended to the message, as SARIF has no fields for them
+    let mut text = diagnostic.message.clone();
+
+    for note in diagnostic.notes.iter() {
+        text.push_str(&format!("\nnote: {}", note));
+    }
+
+    if let Some(help) = &diagnostic.help {
+        text.push_str(&format!("\nhelp: {}", help));
+    }
+
+    let mut result = Vec::new();
+
+    if let Some(id) = rule_id(diagnostic) {
+        result.push(("ruleId", Json::string(id)));
+
+        if let Some(index) = rule_ids.iter().position(|r| *r == id) {
+            result.push(("ruleIndex", Json::Number(index)));
+        }
+    }
+
+    result.push(("level", Json::string(level)));
+    result.push(("message", Json::Object(vec![("text", Json::String(text))])));
+
+    if let Some(location) = location_json(source_map, &diagnostic.primary, None) {
+        result.push(("locations", Json::Array(vec![location])));
+    }
+
+    let related_locations = diagnostic
+        .secondary
+        .iter()
+        .enumerate()
+        .filter_map(|(i, label)| location_json(source_map, label, Some(i)))
+        .collect::<Vec<Json>>();
+
+    if !related_locations.is_empty() {
+        result.push(("relatedLocations", Json::Array(related_locations)));
+    }
+
+    let fixes = diagnostic
+        .suggestions
+        .iter()
+        .filter_map(|s| fix_json(source_map, s))
+        .collect::<Vec<Json>>();
+
+    if !fixes.is_empty() {
+        result.push(("fixes", Json::Array(fixes)));
+    }
+
+    Json::Object(result)
+}
+
+// fix that replaces the span of a suggestion (`None` if the span does not point into a file)
+fn fix_json(source_map: &SourceMap, suggestion: &Suggestion) -> Option<Json> {
+    let file_name = file_name(source_map, suggestion.span)?;
+
+    let replacement = Json::Object(vec![
+        ("deletedRegion", region_json(source_map, suggestion.span)),
+        (
+            "insertedContent",
+            Json::Object(vec![("text", Json::string(&suggestion.replacement))]),
+        ),
+    ]);
+
+    Some(Json::Object(vec![(
+        "artifactChanges",
+        Json::Array(vec![Json::Object(vec![
+            (
+                "artifactLocation",
+                Json::Object(vec![("uri", Json::string(file_name))]),
+            ),
+            ("replacements", Json::Array(vec![replacement])),
+        ])]),
+    )]))
+}
+
+// location of a label (`None` if its span does not point into a file), with an ID if it is
+// a related location
+fn location_json(source_map: &SourceMap, label: &Label, id: Option<usize>) -> Option<Json> {
+    let file_name = file_name(source_map, label.span)?;
+
+    let mut location = Vec::new();
+
+    if let Some(id) = id {
+        location.push(("id", Json::Number(id)));
+    }
+
+    location.push((
+        "physicalLocation",
+        Json::Object(vec![
+            (
+                "artifactLocation",
+                Json::Object(vec![("uri", Json::string(file_name))]),
+            ),
+            ("region", region_json(source_map, label.span)),
+        ]),
+    ));
+
+    if !label.message.is_empty() {
+        location.push((
+            "message",
+            Json::Object(vec![("text", Json::string(&label.message))]),
+        ));
+    }
+
+    Some(Json::Object(location))
+}
+
+fn region_json(source_map: &SourceMap, span: Span) -> Json {
+    let Some(file) = span.source(source_map) else {
+        return Json::Null;
+    };
+
+    let (start_line, start_col) = file.line_col(span.start());
+    let (end_line, end_col) = file.line_col(span.end());
+
+    Json::Object(vec![
+        ("startLine", Json::Number(start_line)),
+        ("startColumn", Json::Number(start_col)),
+        ("endLine", Json::Number(end_line)),
+        ("endColumn", Json::Number(end_col)),
+        ("byteOffset", Json::Number(span.start())),
+        ("byteLength", Json::Number(span.len())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_types::span::Position;
+
+    use crate::{
+        error::CompilerError,
+        lex_error::{LexError, LexErrorKind},
+        warning::{CompilerWarning, WarningKind},
+    };
+
+    use super::*;
+
+    #[test]
+    fn sarif_log() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("output.feo", "foo(a[0);");
+
+        let handler = Handler::default();
+
+        handler.emit_err(CompilerError::Lex(LexError {
+            error_kind: LexErrorKind::MismatchedDelimiters {
+                open: '[',
+                open_position: Position::new(src_id, 5),
+                close: ')',
+            },
+            position: Position::new(src_id, 7),
+        }));
+
+        let sarif = to_sarif(&source_map, &handler);
+
+        assert!(sarif.starts_with(
+            r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json","version":"2.1.0""#
+        ));
+        assert!(sarif.contains(r#""rules":[{"id":"E0114","shortDescription":{"text":"A close delimiter does not match the most recent open delimiter."}}]"#));
+        assert!(sarif.contains(r#""ruleId":"E0114","ruleIndex":0,"level":"error""#));
+        assert!(sarif.contains(r#""relatedLocations":[{"id":0,"physicalLocation":{"artifactLocation":{"uri":"output.feo"},"region":{"startLine":1,"startColumn":6,"endLine":1,"endColumn":7,"byteOffset":5,"byteLength":1}},"message":{"text":"`[` opened here"}}]"#));
+    }
+
+    #[test]
+    fn sarif_lint_rules() {
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lint_output.feo", "const r#foo: u64 = 1;");
+
+        let handler = Handler::default();
+
+        handler.emit_warn(CompilerWarning::new(
+            WarningKind::UnnecessaryRawIdentifier {
+                name: "foo".to_string(),
+            },
+            Span::new(src_id, 6, 11),
+        ));
+
+        let sarif = to_sarif(&source_map, &handler);
+
+        // lints are rules too, named after the lint rather than an error code
+        assert!(sarif.contains(r#""rules":[{"id":"unnecessary_raw_identifier","shortDescription":{"text":"detects raw identifiers (`r#name`) whose name is not a keyword"}}]"#));
+        assert!(sarif
+            .contains(r#""ruleId":"unnecessary_raw_identifier","ruleIndex":0,"level":"warning""#));
+    }
+}
@@ -0,0 +1,223 @@
+use feo_types::{source_map::SourceId, span::Span};
+
+use crate::diagnostic::Diagnostic;
+
+/// How confident the compiler is that a `Suggestion` is what the user meant
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Applicability {
+    /// The suggestion is definitely correct, and can be applied automatically
+    MachineApplicable,
+    /// The suggestion may be correct, but the user should check it
+    MaybeIncorrect,
+    /// The replacement contains placeholders (e.g., `<type>`) that the user must fill in
+    HasPlaceholders,
+    /// The confidence of the suggestion is not known
+    Unspecified,
+}
+
+impl Applicability {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Applicability::MachineApplicable => "machine-applicable",
+            Applicability::MaybeIncorrect => "maybe-incorrect",
+            Applicability::HasPlaceholders => "has-placeholders",
+            Applicability::Unspecified => "unspecified",
+        }
+    }
+}
+
+/// Edit to the source code that fixes a diagnostic, i.e., replace the text in `span`
+/// with `replacement` (an empty span inserts the replacement, and an empty replacement
+/// deletes the text in the span)
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub span: Span,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(span: Span, replacement: &str, applicability: Applicability) -> Self {
+        Self {
+            span,
+            replacement: replacement.to_string(),
+            applicability,
+        }
+    }
+
+    /// Insert `text` at byte offset `pos` of a file.
+    pub fn insertion(
+        src_id: SourceId,
+        pos: usize,
+        text: &str,
+        applicability: Applicability,
+    ) -> Self {
+        Suggestion::new(Span::new(src_id, pos, pos), text, applicability)
+    }
+
+    pub fn is_machine_applicable(&self) -> bool {
+        self.applicability == Applicability::MachineApplicable
+    }
+}
+
+/// Apply the machine-applicable suggestions of the diagnostics to `source`, and return
+/// the fixed source code.
+/// The spans of the suggestions are byte offsets into `source`, so the diagnostics should
+/// be about that file only.
+/// Suggestions are applied in order of their spans; a suggestion that overlaps one that was
+/// already applied (or that does not fit into `source`) is skipped, as is a second insertion
+/// of the same text at the same position (e.g., two errors that both want a `;`).
+pub fn apply_suggestions(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut suggestions = diagnostics
+        .iter()
+        .flat_map(|d| d.suggestions.iter())
+        .filter(|s| s.is_machine_applicable())
+        .filter(|s| {
+            s.span.end() <= source.len()
+                && source.is_char_boundary(s.span.start())
+                && source.is_char_boundary(s.span.end())
+        })
+        .collect::<Vec<&Suggestion>>();
+
+    // stable sort, so that insertions at the same position keep the order they were made in
+    suggestions.sort_by_key(|s| (s.span.start(), s.span.end()));
+
+    let mut fixed = String::with_capacity(source.len());
+    let mut pos = 0;
+    let mut prev: Option<&Suggestion> = None;
+
+    for s in suggestions {
+        let is_duplicate = prev.is_some_and(|p| {
+            p.span.start() == s.span.start()
+                && p.span.end() == s.span.end()
+                && p.replacement == s.replacement
+        });
+
+        if is_duplicate || s.span.start() < pos {
+            continue;
+        }
+
+        fixed.push_str(&source[pos..s.span.start()]);
+        fixed.push_str(&s.replacement);
+        pos = s.span.end();
+        prev = Some(s);
+    }
+
+    fixed.push_str(&source[pos..]);
+    fixed
+}
+
+/// Return the number of single-char edits (insertions, deletions, substitutions and swaps of
+/// adjacent chars) that turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<char>>();
+    let b = b.chars().collect::<Vec<char>>();
+
+    // distances between prefixes of `a` and `b`, one row per prefix of `a`
+    let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+
+        rows.push(row);
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// Return the candidate that is closest to `word` (e.g., for a "did you mean" suggestion),
+/// or `None` if no candidate is close enough, i.e., within one edit per three chars of `word`.
+/// A candidate that only differs in case is always close enough, and ties go to the first
+/// candidate.
+pub fn find_best_match<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (word.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|c| *c != word)
+        .map(|c| {
+            let distance = if c.to_lowercase() == word.to_lowercase() {
+                0
+            } else {
+                edit_distance(word, c)
+            };
+
+            (distance, c)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_types::source_map::SourceMap;
+
+    use super::*;
+
+    #[test]
+    fn apply_machine_applicable_suggestions() {
+        let source_code = "func foo() {\n    bar(baz[0\n";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("fix.feo", source_code);
+
+        let closes = Diagnostic::error("unclosed delimiters", Span::new(src_id, 11, 12))
+            .with_suggestion(Suggestion::insertion(
+                src_id,
+                source_code.len(),
+                "])}",
+                Applicability::MachineApplicable,
+            ));
+
+        assert_eq!(
+            apply_suggestions(source_code, &[closes]),
+            "func foo() {\n    bar(baz[0\n])}"
+        );
+
+        // only machine-applicable suggestions are applied, and duplicates only once
+        let semicolon = Diagnostic::error("expected `;`", Span::new(src_id, 0, 0)).with_suggestion(
+            Suggestion::insertion(src_id, 12, ";", Applicability::MachineApplicable),
+        );
+        let brace = Diagnostic::error("expected `}`", Span::new(src_id, 0, 0)).with_suggestion(
+            Suggestion::insertion(src_id, 12, "}", Applicability::MaybeIncorrect),
+        );
+
+        assert_eq!(
+            apply_suggestions(source_code, &[semicolon.clone(), semicolon, brace]),
+            "func foo() {;\n    bar(baz[0\n"
+        );
+
+        // a suggestion that overlaps one that was already applied is skipped
+        let replace = |start: usize, end: usize, text: &str| {
+            Diagnostic::error("replace", Span::new(src_id, start, end)).with_suggestion(
+                Suggestion::new(
+                    Span::new(src_id, start, end),
+                    text,
+                    Applicability::MachineApplicable,
+                ),
+            )
+        };
+
+        assert_eq!(
+            apply_suggestions(source_code, &[replace(0, 4, "fn"), replace(2, 6, "x")]),
+            "fn foo() {\n    bar(baz[0\n"
+        );
+    }
+}
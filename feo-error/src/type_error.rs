@@ -13,12 +13,6 @@ impl Error for TypeError {}
 
 impl fmt::Display for TypeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}:{}",
-            self.error_kind,
-            self.position.line_col().0,
-            self.position.line_col().1
-        )
+        write!(f, "{}", self.error_kind)
     }
 }
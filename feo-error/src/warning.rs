@@ -1,8 +1,50 @@
-#[derive(Debug, Clone)]
-pub enum WarningKind {}
+use std::fmt;
+
+use feo_types::span::Span;
+
+use crate::lint::{self, Lint};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    UnnecessaryRawIdentifier { name: String },
+    UnknownLint { name: String },
+}
+
+impl WarningKind {
+    /// Return the lint that reports the warning (which decides its level).
+    pub fn lint(&self) -> &'static Lint {
+        match self {
+            WarningKind::UnnecessaryRawIdentifier { .. } => &lint::UNNECESSARY_RAW_IDENTIFIER,
+            WarningKind::UnknownLint { .. } => &lint::UNKNOWN_LINT,
+        }
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningKind::UnnecessaryRawIdentifier { name } => {
+                write!(f, "unnecessary raw identifier: `{}` is not a keyword", name)
+            }
+            WarningKind::UnknownLint { name } => write!(f, "unknown lint: `{}`", name),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct CompilerWarning {
     pub warning_kind: WarningKind,
-    pub pos: usize,
+    pub span: Span,
+}
+
+impl CompilerWarning {
+    pub fn new(warning_kind: WarningKind, span: Span) -> Self {
+        Self { warning_kind, span }
+    }
+}
+
+impl fmt::Display for CompilerWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.warning_kind)
+    }
 }
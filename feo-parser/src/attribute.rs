@@ -1,8 +1,10 @@
+use std::str::FromStr;
+
 use feo_ast::{
-    attribute::{AttributeKind, InnerAttr, OuterAttr},
+    attribute::{AttributeKind, InnerAttr, LintAttr, OuterAttr},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, lint::LintLevel, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -22,7 +24,11 @@ impl Peek for AttributeKind {
     where
         Self: Sized,
     {
-        let attr_kind = if let Some(k) = Keyword::peek(peeker) {
+        // contextual keywords are lexed as identifiers, and only recognised by their text here
+        let keyword_opt = Keyword::peek(peeker)
+            .or_else(|| Identifier::peek(peeker).and_then(|id| id.as_contextual_keyword()));
+
+        let attr_kind = if let Some(k) = keyword_opt {
             match &k.keyword_kind {
                 KeywordKind::KwAbstract => AttributeKind::KwAbstract(k),
                 KeywordKind::KwContract => AttributeKind::KwContract(k),
@@ -46,7 +52,7 @@ impl Peek for AttributeKind {
 }
 
 impl ParseTerm for InnerAttr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -68,9 +74,7 @@ impl ParseTerm for InnerAttr {
             {
                 parser.next_token();
 
-                if let Some(attribute) = parser.peek_next::<AttributeKind>() {
-                    parser.next_token();
-
+                if let Some(attribute) = get_attribute_kind(parser)? {
                     test_utils::log_msg(LogMsgType::Detect, "attribute kind", parser);
 
                     let close_bracket_opt = parser.peek_next();
@@ -115,7 +119,7 @@ impl ParseTerm for InnerAttr {
 }
 
 impl ParseTerm for OuterAttr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -137,9 +141,7 @@ impl ParseTerm for OuterAttr {
             {
                 parser.next_token();
 
-                if let Some(attribute) = parser.peek_next::<AttributeKind>() {
-                    parser.next_token();
-
+                if let Some(attribute) = get_attribute_kind(parser)? {
                     test_utils::log_msg(LogMsgType::Detect, "attribute kind", parser);
 
                     let close_bracket_opt = parser.peek_next();
@@ -183,13 +185,91 @@ impl ParseTerm for OuterAttr {
     }
 }
 
+// parse the `AttributeKind` after the open bracket (the current token) of an attribute,
+// leaving the parser on its last token
+fn get_attribute_kind(parser: &mut Parser) -> Result<Option<AttributeKind>, Vec<Diagnostic>> {
+    let attribute_opt = parser.peek_next::<AttributeKind>();
+
+    if let Some(AttributeKind::Identifier(id)) = &attribute_opt {
+        if let (Ok(_), Some(open_parenthesis)) = (
+            LintLevel::from_str(&id.name),
+            parser.peek_ahead::<Delimiter>(2),
+        ) {
+            if let (DelimKind::Parenthesis, DelimOrientation::Open) = open_parenthesis.delim {
+                parser.next_token();
+                parser.next_token();
+
+                return get_lint_attr(parser, id.clone(), open_parenthesis)
+                    .map(|la| Some(AttributeKind::Lint(la)));
+            }
+        }
+    }
+
+    if attribute_opt.is_some() {
+        parser.next_token();
+    }
+
+    Ok(attribute_opt)
+}
+
+// parse the comma-separated lint names of a lint attribute (e.g., `allow(a, b)`),
+// starting at the open parenthesis, and leaving the parser on the close parenthesis
+fn get_lint_attr(
+    parser: &mut Parser,
+    level: Identifier,
+    open_parenthesis: Delimiter,
+) -> Result<LintAttr, Vec<Diagnostic>> {
+    let mut lints: Vec<Identifier> = Vec::new();
+
+    while let Some(lint) = parser.peek_next::<Identifier>() {
+        parser.next_token();
+        lints.push(lint);
+
+        if let Some(Punctuation {
+            punc_kind: PuncKind::Comma,
+            ..
+        }) = parser.peek_next()
+        {
+            parser.next_token();
+        } else {
+            break;
+        }
+    }
+
+    if let Some(
+        close_parenthesis @ Delimiter {
+            delim: (DelimKind::Parenthesis, DelimOrientation::Close),
+            ..
+        },
+    ) = parser.peek_next()
+    {
+        parser.next_token();
+
+        return Ok(LintAttr {
+            level,
+            open_parenthesis,
+            lints,
+            close_parenthesis,
+        });
+    }
+
+    parser.next_token();
+
+    parser.log_error(ParserErrorKind::UnexpectedToken {
+        expected: "`)`".to_string(),
+        found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+    });
+
+    Err(parser.errors())
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
     #[test]
-    fn parse_attribute_inner() -> Result<(), Vec<CompilerError>> {
+    fn parse_attribute_inner() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"#![unsafe]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -200,7 +280,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_attribute_outer() -> Result<(), Vec<CompilerError>> {
+    fn parse_attribute_outer() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"#[unsafe]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -209,4 +289,56 @@ mod tests {
 
         Ok(println!("{:#?}", outer_attr))
     }
+
+    #[test]
+    fn parse_contextual_keyword_attribute() -> Result<(), Vec<Diagnostic>> {
+        let source_code = r#"#[storage]"#;
+
+        let mut parser = test_utils::get_parser(source_code, false)?;
+
+        let outer_attr = OuterAttr::parse(&mut parser)?.expect("unable to parse outer attribute");
+
+        assert!(matches!(outer_attr.attribute, AttributeKind::KwStorage(_)));
+
+        // a raw identifier is never a keyword
+        let mut parser = test_utils::get_parser(r#"#[r#storage]"#, false)?;
+
+        let outer_attr = OuterAttr::parse(&mut parser)?.expect("unable to parse outer attribute");
+
+        assert!(matches!(outer_attr.attribute, AttributeKind::Identifier(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_lint_attribute() -> Result<(), Vec<Diagnostic>> {
+        let source_code = r#"#[allow(unknown_lint, unnecessary_raw_identifier)]"#;
+
+        let mut parser = test_utils::get_parser(source_code, false)?;
+
+        let outer_attr = OuterAttr::parse(&mut parser)?.expect("unable to parse outer attribute");
+
+        let AttributeKind::Lint(lint_attr) = outer_attr.attribute else {
+            panic!("expected lint attribute, found {:?}", outer_attr.attribute);
+        };
+
+        assert_eq!(lint_attr.level.name, "allow");
+        assert_eq!(
+            lint_attr
+                .lints
+                .iter()
+                .map(|l| l.name.as_str())
+                .collect::<Vec<&str>>(),
+            ["unknown_lint", "unnecessary_raw_identifier"]
+        );
+
+        // an identifier attribute is not a lint attribute without a lint list
+        let mut parser = test_utils::get_parser(r#"#[deny]"#, false)?;
+
+        let outer_attr = OuterAttr::parse(&mut parser)?.expect("unable to parse outer attribute");
+
+        assert!(matches!(outer_attr.attribute, AttributeKind::Identifier(_)));
+
+        Ok(())
+    }
 }
@@ -2,7 +2,7 @@ use feo_ast::{
     expression::{ArrayExpr, IndexExpr, Value},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -15,7 +15,7 @@ use crate::{
 };
 
 impl ParseExpr for ArrayExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -57,7 +57,7 @@ impl ParseExpr for ArrayExpr {
 }
 
 impl ParseExpr for IndexExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -82,7 +82,7 @@ impl ParseExpr for IndexExpr {
                     }) = close_bracket_opt
                     {
                         parser.next_token();
-                        
+
                         return Ok(Some(IndexExpr {
                             indexed_operand: Box::new(indexed_operand),
                             open_bracket: open_bracket_opt.unwrap(),
@@ -120,7 +120,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_array_expr_with_elements() -> Result<(), Vec<CompilerError>> {
+    fn parse_array_expr_with_elements() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"[1, 2, 3, 4,]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -131,7 +131,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_array_empty() -> Result<(), Vec<CompilerError>> {
+    fn parse_array_empty() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"[]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -142,7 +142,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_index_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_index_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo[1]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -2,7 +2,7 @@ use feo_ast::{
     expression::{BlockExpr, ExprWithoutBlock},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -16,7 +16,7 @@ use crate::{
 };
 
 impl ParseExpr for BlockExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -24,58 +24,73 @@ impl ParseExpr for BlockExpr {
 
         let open_brace_opt = parser.peek_current();
 
-        if let Some(Delimiter {
-            delim: (DelimKind::Brace, DelimOrientation::Open),
-            ..
-        }) = open_brace_opt
+        if let Some(
+            open_brace @ Delimiter {
+                delim: (DelimKind::Brace, DelimOrientation::Open),
+                ..
+            },
+        ) = open_brace_opt
         {
-            parser.next_token();
-
-            let statements_opt = utils::get_statements(parser)?;
-
-            let final_operand_opt = if let Some(e) = ExprWithoutBlock::parse(parser)? {
-                parser.next_token();
-                Some(Box::new(e))
-            } else {
-                None
-            };
+            // after an error anywhere inside the block, skip to its `}`, so that the caller
+            // can go on parsing after the block
+            let block_expr =
+                parser.parse_group(|parser| parse_block_contents(parser, open_brace))?;
 
-            let close_brace_opt = parser.peek_current();
+            test_utils::log_msg(LogMsgType::Exit, "block expression", parser);
 
-            if let Some(Delimiter {
-                delim: (DelimKind::Brace, DelimOrientation::Close),
-                ..
-            }) = close_brace_opt
-            {
-                test_utils::log_msg(LogMsgType::Exit, "block expression", parser);
-
-                return Ok(Some(BlockExpr {
-                    open_brace: open_brace_opt.unwrap(),
-                    statements_opt,
-                    final_operand_opt,
-                    close_brace: close_brace_opt.unwrap(),
-                }));
-            }
-
-            parser.log_error(ParserErrorKind::UnexpectedToken {
-                expected: "`}`".to_string(),
-                found: parser.current_token().unwrap_or(Token::EOF).to_string(),
-            });
+            Ok(Some(block_expr))
         } else {
-            return Ok(None);
+            Ok(None)
         }
+    }
+}
 
-        Err(parser.errors())
+fn parse_block_contents(
+    parser: &mut Parser,
+    open_brace: Delimiter,
+) -> Result<BlockExpr, Vec<Diagnostic>> {
+    parser.next_token();
+
+    let statements_opt = utils::get_statements(parser)?;
+
+    let final_operand_opt = if let Some(e) = ExprWithoutBlock::parse(parser)? {
+        parser.next_token();
+        Some(Box::new(e))
+    } else {
+        None
+    };
+
+    let close_brace_opt = parser.peek_current();
+
+    if let Some(Delimiter {
+        delim: (DelimKind::Brace, DelimOrientation::Close),
+        ..
+    }) = close_brace_opt
+    {
+        return Ok(BlockExpr {
+            open_brace,
+            statements_opt,
+            final_operand_opt,
+            close_brace: close_brace_opt.unwrap(),
+        });
     }
+
+    parser.log_error(ParserErrorKind::UnexpectedToken {
+        expected: "`}`".to_string(),
+        found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+    });
+
+    Err(parser.errors())
 }
 
 #[cfg(test)]
 mod tests {
+    use feo_types::{source_map::SourceMap, span::Spanned};
 
     use super::*;
 
     #[test]
-    fn parse_block_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_block_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         {
             foo(bar, 12, true);
@@ -87,4 +102,30 @@ mod tests {
 
         Ok(println!("{:#?}", block_expr))
     }
+
+    #[test]
+    fn skip_broken_block() {
+        let mut source_map = SourceMap::new();
+
+        let source_code = "{ let x = 1; foo(x, { y }); } bar";
+
+        let mut parser =
+            test_utils::get_parser_with_source_map(&mut source_map, source_code, false)
+                .expect("unable to lex");
+
+        BlockExpr::parse(&mut parser).expect_err("block argument not reported");
+
+        // the error is in the nested `( .. )`, but the whole block is skipped
+        assert_eq!(
+            parser.current_token().map(|t| t.span().start()),
+            Some(source_code.len() - 5)
+        );
+
+        parser.next_token();
+
+        assert_eq!(
+            parser.current_token().map(|t| t.span().as_str(&source_map)),
+            Some("bar")
+        );
+    }
 }
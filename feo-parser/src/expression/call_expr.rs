@@ -3,7 +3,7 @@ use feo_ast::{
     path::PathInExpr,
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     punctuation::PuncKind,
@@ -17,7 +17,7 @@ use crate::{
 };
 
 impl ParseExpr for FunctionCallExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -65,59 +65,66 @@ impl ParseExpr for FunctionCallExpr {
 }
 
 impl ParseExpr for MethodCallExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
-        if let Some(receiver) = Value::parse(parser)? {
-            let full_stop_opt = parser.peek_next::<Punctuation>();
+        // the receiver and `.` are consumed before it is known whether a method name and
+        // arguments follow (e.g., in `t.0` or `x.name`), so go back to the receiver if not
+        parser.try_parse(parse_method_call_expr)
+    }
+}
 
-            if let Some(Punctuation {
-                punc_kind: PuncKind::FullStop,
-                ..
-            }) = full_stop_opt
-            {
+fn parse_method_call_expr(parser: &mut Parser) -> Result<Option<MethodCallExpr>, Vec<Diagnostic>> {
+    if let Some(receiver) = Value::parse(parser)? {
+        // the receiver may be a tuple index chain (e.g., `x.1.0.method()`)
+        let receiver = utils::get_tuple_index_chain(parser, receiver)?;
+
+        let full_stop_opt = parser.peek_next::<Punctuation>();
+
+        if let Some(Punctuation {
+            punc_kind: PuncKind::FullStop,
+            ..
+        }) = full_stop_opt
+        {
+            parser.next_token();
+
+            if let Some(method_name) = parser.peek_next::<Identifier>() {
                 parser.next_token();
 
-                if let Some(method_name) = parser.peek_next::<Identifier>() {
-                    parser.next_token();
+                let open_parenthesis_opt = parser.peek_next::<Delimiter>();
+                parser.next_token();
 
-                    let open_parenthesis_opt = parser.peek_next::<Delimiter>();
+                if let Some(Delimiter {
+                    delim: (DelimKind::Parenthesis, DelimOrientation::Open),
+                    ..
+                }) = open_parenthesis_opt
+                {
                     parser.next_token();
 
+                    let call_params_opt = utils::get_value_collection(parser)?;
+
+                    let close_parenthesis_opt = parser.peek_current::<Delimiter>();
+
                     if let Some(Delimiter {
-                        delim: (DelimKind::Parenthesis, DelimOrientation::Open),
+                        delim: (DelimKind::Parenthesis, DelimOrientation::Close),
                         ..
-                    }) = open_parenthesis_opt
+                    }) = close_parenthesis_opt
                     {
-                        parser.next_token();
-
-                        let call_params_opt = utils::get_value_collection(parser)?;
-
-                        let close_parenthesis_opt = parser.peek_current::<Delimiter>();
-
-                        if let Some(Delimiter {
-                            delim: (DelimKind::Parenthesis, DelimOrientation::Close),
-                            ..
-                        }) = close_parenthesis_opt
-                        {
-                            return Ok(Some(MethodCallExpr {
-                                receiver: Box::new(receiver),
-                                full_stop: full_stop_opt.unwrap(),
-                                method_name,
-                                open_parenthesis: open_parenthesis_opt.unwrap(),
-                                call_params_opt,
-                                close_parenthesis: close_parenthesis_opt.unwrap(),
-                            }));
-                        }
-
-                        parser.log_error(ParserErrorKind::UnexpectedToken {
-                            expected: "`)`".to_string(),
-                            found: parser.current_token().unwrap_or(Token::EOF).to_string(),
-                        });
-                    } else {
-                        return Ok(None);
+                        return Ok(Some(MethodCallExpr {
+                            receiver: Box::new(receiver),
+                            full_stop: full_stop_opt.unwrap(),
+                            method_name,
+                            open_parenthesis: open_parenthesis_opt.unwrap(),
+                            call_params_opt,
+                            close_parenthesis: close_parenthesis_opt.unwrap(),
+                        }));
                     }
+
+                    parser.log_error(ParserErrorKind::UnexpectedToken {
+                        expected: "`)`".to_string(),
+                        found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+                    });
                 } else {
                     return Ok(None);
                 }
@@ -127,19 +134,24 @@ impl ParseExpr for MethodCallExpr {
         } else {
             return Ok(None);
         }
-
-        Err(parser.errors())
+    } else {
+        return Ok(None);
     }
+
+    Err(parser.errors())
 }
 
 #[cfg(test)]
 mod tests {
+    use feo_error::suggestion::apply_suggestions;
+    use feo_types::source_map::SourceMap;
+
     use crate::test_utils;
 
     use super::*;
 
     #[test]
-    fn parse_call_expr_function() -> Result<(), Vec<CompilerError>> {
+    fn parse_call_expr_function() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo(bar, "a", 1)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -151,7 +163,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_call_expr_method() -> Result<(), Vec<CompilerError>> {
+    fn parse_call_expr_method() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo.bar(baz, "a", 1)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -161,4 +173,23 @@ mod tests {
 
         Ok(println!("{:#?}", method_call_expr))
     }
+
+    #[test]
+    fn fix_missing_comma() {
+        let mut source_map = SourceMap::new();
+
+        let source_code = r#"foo(bar, "a" 1)"#;
+
+        let mut parser =
+            test_utils::get_parser_with_source_map(&mut source_map, source_code, false)
+                .expect("unable to lex");
+
+        let errors = FunctionCallExpr::parse(&mut parser).expect_err("missing `,` not reported");
+
+        assert_eq!(errors[0].line_col(&source_map), (1, 14));
+        assert_eq!(
+            apply_suggestions(source_code, &errors),
+            r#"foo(bar, "a", 1)"#
+        );
+    }
 }
@@ -7,7 +7,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{punctuation::PuncKind, Punctuation};
 
 use crate::{
@@ -17,7 +17,7 @@ use crate::{
 };
 
 impl ParseTerm for ClosureParamsOpt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -70,7 +70,7 @@ impl ParseTerm for ClosureParamsOpt {
 }
 
 impl ParseTerm for ClosureParam {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -108,7 +108,7 @@ impl ParseTerm for ClosureParam {
 }
 
 impl ParseExpr for ClosureWithoutBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -135,7 +135,7 @@ impl ParseExpr for ClosureWithoutBlock {
 }
 
 impl ParseExpr for ClosureWithBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -186,7 +186,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_closure_param() -> Result<(), Vec<CompilerError>> {
+    fn parse_closure_param() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         #[export]
@@ -202,7 +202,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_closure_without_block() -> Result<(), Vec<CompilerError>> {
+    fn parse_closure_without_block() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"|foo: u64| return bar"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -214,7 +214,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_closure_with_block() -> Result<(), Vec<CompilerError>> {
+    fn parse_closure_with_block() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"|foo: u64| -> bool {
             foo * 12;
             true
@@ -6,7 +6,7 @@ use feo_ast::{
     pattern::Pattern,
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -22,7 +22,7 @@ use crate::{
 };
 
 impl ParseExpr for IfExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -119,7 +119,7 @@ impl ParseExpr for IfExpr {
 }
 
 impl ParseTerm for MatchArmGuard {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -152,7 +152,7 @@ impl ParseTerm for MatchArmGuard {
 }
 
 impl ParseTerm for MatchArm {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -179,7 +179,7 @@ impl ParseTerm for MatchArm {
 }
 
 impl ParseTerm for MatchArms {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -238,7 +238,7 @@ impl ParseTerm for MatchArms {
 }
 
 impl ParseExpr for MatchExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -330,7 +330,7 @@ impl ParseExpr for MatchExpr {
     }
 }
 
-fn get_arm(parser: &mut Parser) -> Result<Option<(MatchArm, Expression)>, Vec<CompilerError>> {
+fn get_arm(parser: &mut Parser) -> Result<Option<(MatchArm, Expression)>, Vec<Diagnostic>> {
     if let Some(arm) = MatchArm::parse(parser)? {
         if let Some(Punctuation {
             punc_kind: PuncKind::FatArrow,
@@ -367,7 +367,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_match_arm_guard() -> Result<(), Vec<CompilerError>> {
+    fn parse_match_arm_guard() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"if x > 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -379,7 +379,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_match_arm() -> Result<(), Vec<CompilerError>> {
+    fn parse_match_arm() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         true
@@ -393,7 +393,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_match_arms() -> Result<(), Vec<CompilerError>> {
+    fn parse_match_arms() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         true => x + 2,
@@ -408,7 +408,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_match_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_match_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         match foo {
             #![unsafe]
@@ -426,7 +426,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_if_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_if_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         if (foo < 2) { 
             bar(x) 
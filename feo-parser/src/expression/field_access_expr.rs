@@ -2,60 +2,72 @@ use feo_ast::{
     expression::{FieldAccessExpr, Value},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{punctuation::PuncKind, Identifier, Punctuation};
 
 use crate::{
     parse::{ParseExpr, ParseTerm},
     parser::Parser,
     test_utils::{self, LogMsgType},
+    utils,
 };
 
 impl ParseExpr for FieldAccessExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
-        if let Some(container_operand) = Value::parse(parser)? {
-            test_utils::log_msg(
-                LogMsgType::Detect,
-                "field access expression container operand",
-                parser,
-            );
-
-            if let Some(Punctuation {
-                punc_kind: PuncKind::FullStop,
-                ..
-            }) = parser.peek_next()
-            {
-                parser.next_token();
-
-                test_utils::log_msg(LogMsgType::Detect, "full stop", parser);
-
-                if let Some(field_name) = parser.peek_next::<Identifier>() {
-                    parser.next_token();
+        // the container and `.` are consumed before it is known whether a field name follows
+        // (e.g., in `t.0`), so go back to the container if not
+        parser.try_parse(parse_field_access_expr)
+    }
+}
 
-                    test_utils::log_msg(LogMsgType::Detect, "identifier", parser);
+fn parse_field_access_expr(
+    parser: &mut Parser,
+) -> Result<Option<FieldAccessExpr>, Vec<Diagnostic>> {
+    if let Some(container_operand) = Value::parse(parser)? {
+        // the container may be a tuple index chain (e.g., `x.0.name`)
+        let container_operand = utils::get_tuple_index_chain(parser, container_operand)?;
+
+        test_utils::log_msg(
+            LogMsgType::Detect,
+            "field access expression container operand",
+            parser,
+        );
+
+        if let Some(Punctuation {
+            punc_kind: PuncKind::FullStop,
+            ..
+        }) = parser.peek_next()
+        {
+            parser.next_token();
+
+            test_utils::log_msg(LogMsgType::Detect, "full stop", parser);
+
+            if let Some(field_name) = parser.peek_next::<Identifier>() {
+                parser.next_token();
 
-                    return Ok(Some(FieldAccessExpr {
-                        container_operand: Box::new(container_operand),
-                        field_name,
-                    }));
-                }
+                test_utils::log_msg(LogMsgType::Detect, "identifier", parser);
 
-                parser.log_error(ParserErrorKind::UnexpectedToken {
-                    expected: "identifier".to_string(),
-                    found: parser.current_token().unwrap_or(Token::EOF).to_string(),
-                });
-            } else {
-                return Ok(None);
+                return Ok(Some(FieldAccessExpr {
+                    container_operand: Box::new(container_operand),
+                    field_name,
+                }));
             }
+
+            parser.log_error(ParserErrorKind::UnexpectedToken {
+                expected: "identifier".to_string(),
+                found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+            });
         } else {
             return Ok(None);
         }
-
-        Err(parser.errors())
+    } else {
+        return Ok(None);
     }
+
+    Err(parser.errors())
 }
 
 #[cfg(test)]
@@ -66,7 +78,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_field_access_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_field_access_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"hello.world"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -6,7 +6,7 @@ use feo_ast::{
     pattern::Pattern,
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, Keyword};
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 };
 
 impl ParseExpr for BreakExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -34,7 +34,7 @@ impl ParseExpr for BreakExpr {
 }
 
 impl ParseExpr for ContinueExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -53,7 +53,7 @@ impl ParseExpr for ContinueExpr {
 }
 
 impl ParseExpr for InfiniteLoopExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -86,7 +86,7 @@ impl ParseExpr for InfiniteLoopExpr {
 }
 
 impl ParseExpr for PredicateLoopExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -129,7 +129,7 @@ impl ParseExpr for PredicateLoopExpr {
 }
 
 impl ParseExpr for IterLoopExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -153,7 +153,7 @@ impl ParseExpr for IterLoopExpr {
                 }) = kw_in_opt
                 {
                     parser.next_token();
-                    
+
                     if let Some(iterator) = ParenthesizedExpr::parse(parser)? {
                         parser.next_token();
 
@@ -200,7 +200,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_break_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_break_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"break"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -211,7 +211,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_continue_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_continue_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"continue"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -223,7 +223,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_infinite_loop_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_infinite_loop_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         loop {
             foo += 2
@@ -238,7 +238,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_predicate_loop_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_predicate_loop_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         while (foo < 100) {
             foo += 2
@@ -253,7 +253,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_iter_loop_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_iter_loop_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         for x in (1..10) {
             y += 2
@@ -14,6 +14,10 @@ impl Peek for LiteralKind {
             Some(LiteralKind::Char(c))
         } else if let Some(s) = Literal::<String>::peek(peeker) {
             Some(LiteralKind::String(s))
+        } else if let Some(b) = Literal::<u8>::peek(peeker) {
+            Some(LiteralKind::Byte(b))
+        } else if let Some(bs) = Literal::<Vec<u8>>::peek(peeker) {
+            Some(LiteralKind::ByteString(bs))
         } else if let Some(b) = Literal::<bool>::peek(peeker) {
             Some(LiteralKind::Bool(b))
         } else if let Some(i) = Literal::<IntType>::peek(peeker) {
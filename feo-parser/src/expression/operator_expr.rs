@@ -8,7 +8,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Keyword, Punctuation};
 
 use crate::{
@@ -76,6 +76,13 @@ impl Peek for CompoundAssignOperatorKind {
                 PuncKind::PlusEquals => Some(CompoundAssignOperatorKind::AddAssign(p)),
                 PuncKind::MinusEquals => Some(CompoundAssignOperatorKind::SubtractAssign(p)),
                 PuncKind::ForwardSlashEquals => Some(CompoundAssignOperatorKind::DivideAssign(p)),
+                PuncKind::AmpersandEquals => Some(CompoundAssignOperatorKind::BitwiseAndAssign(p)),
+                PuncKind::PipeEquals => Some(CompoundAssignOperatorKind::BitwiseOrAssign(p)),
+                PuncKind::CaretEquals => Some(CompoundAssignOperatorKind::BitwiseXorAssign(p)),
+                PuncKind::DblLessThanEquals => Some(CompoundAssignOperatorKind::ShiftLeftAssign(p)),
+                PuncKind::DblGreaterThanEquals => {
+                    Some(CompoundAssignOperatorKind::ShiftRightAssign(p))
+                }
                 _ => None,
             }
         } else {
@@ -119,7 +126,7 @@ impl Peek for NegationOperatorKind {
 }
 
 impl ParseExpr for ArithmeticOrLogicalExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -170,7 +177,7 @@ impl ParseExpr for ArithmeticOrLogicalExpr {
 }
 
 impl ParseExpr for AssignmentExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -209,7 +216,7 @@ impl ParseExpr for AssignmentExpr {
 }
 
 impl ParseExpr for CompoundAssignmentExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -242,7 +249,7 @@ impl ParseExpr for CompoundAssignmentExpr {
 }
 
 impl ParseExpr for ComparisonExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -290,7 +297,7 @@ impl ParseExpr for ComparisonExpr {
 }
 
 impl ParseExpr for DereferenceExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -323,7 +330,7 @@ impl ParseExpr for DereferenceExpr {
 }
 
 impl ParseExpr for LazyBoolExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -352,7 +359,7 @@ impl ParseExpr for LazyBoolExpr {
 }
 
 impl ParseExpr for NegationExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -379,7 +386,7 @@ impl ParseExpr for NegationExpr {
 }
 
 impl ParseExpr for ReferenceExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -424,7 +431,7 @@ impl ParseExpr for ReferenceExpr {
 }
 
 impl ParseExpr for TypeCastExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -463,7 +470,7 @@ impl ParseExpr for TypeCastExpr {
 }
 
 impl ParseExpr for UnwrapExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -496,7 +503,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_arithmetic_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_arithmetic_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo.bar + 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -508,7 +515,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_logical_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_logical_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1 | 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -520,7 +527,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_assignment_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_assignment_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"x = 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -532,7 +539,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_compound_assignment_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_compound_assignment_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"x += 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -544,7 +551,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_comparison_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_comparison_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"x > 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -556,7 +563,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_dereference_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_dereference_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"*x"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -568,7 +575,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_lazy_bool_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_lazy_bool_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"x && y"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -580,7 +587,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_negation_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_negation_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"!x"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -592,7 +599,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_reference_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_reference_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"&mut x"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -604,7 +611,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_type_cast_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_type_cast_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1 as f64"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -616,7 +623,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_unwrap_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_unwrap_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo?"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
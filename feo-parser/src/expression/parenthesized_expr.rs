@@ -1,5 +1,5 @@
 use feo_ast::expression::{Expression, ParenthesizedExpr};
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -12,7 +12,7 @@ use crate::{
 };
 
 impl ParseTerm for ParenthesizedExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -63,7 +63,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_parenthesized_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_parenthesized_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(foo.bar + 2)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -5,7 +5,7 @@ use feo_ast::{
     },
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{punctuation::PuncKind, Punctuation};
 
 use crate::{
@@ -14,7 +14,7 @@ use crate::{
 };
 
 impl ParseExpr for RangeFullExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -33,7 +33,7 @@ impl ParseExpr for RangeFullExpr {
 }
 
 impl ParseExpr for RangeFromToExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -72,7 +72,7 @@ impl ParseExpr for RangeFromToExpr {
 }
 
 impl ParseExpr for RangeFromExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -100,7 +100,7 @@ impl ParseExpr for RangeFromExpr {
 }
 
 impl ParseExpr for RangeToExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -133,7 +133,7 @@ impl ParseExpr for RangeToExpr {
 }
 
 impl ParseExpr for RangeInclusiveExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -172,7 +172,7 @@ impl ParseExpr for RangeInclusiveExpr {
 }
 
 impl ParseExpr for RangeToInclusiveExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -212,7 +212,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_range_full_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_full_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#".."#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -224,7 +224,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_from_to_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_from_to_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1..5"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -236,7 +236,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_from_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_from_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1.."#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -248,7 +248,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_to_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_to_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"..1"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -260,7 +260,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_inclusive_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_inclusive_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1..=5"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -272,7 +272,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_to_inclusive_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_to_inclusive_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"..=1"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -1,5 +1,5 @@
 use feo_ast::expression::{Expression, ReturnExpr};
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{keyword::KeywordKind, Keyword};
 
 use crate::{
@@ -9,7 +9,7 @@ use crate::{
 };
 
 impl ParseExpr for ReturnExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -50,7 +50,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_return_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_return_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"return x + 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
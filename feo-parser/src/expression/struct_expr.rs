@@ -4,7 +4,7 @@ use feo_ast::{
     token::Token,
 };
 
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
@@ -21,7 +21,7 @@ use crate::{
 };
 
 impl ParseTerm for StructExprField {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -64,7 +64,7 @@ impl ParseTerm for StructExprField {
 }
 
 impl ParseExpr for StructExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -126,7 +126,7 @@ impl ParseExpr for StructExpr {
 }
 
 impl ParseExpr for TupleStructExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -179,7 +179,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_struct_expr_field() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_expr_field() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
             #[abstract]
             #[unsafe]
@@ -195,7 +195,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_struct_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         SomeStruct {
             foo: "a",
@@ -212,7 +212,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_tuple_struct_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_struct_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"SomeStruct(foo, "a", x)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
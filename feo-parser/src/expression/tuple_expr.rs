@@ -2,12 +2,10 @@ use feo_ast::{
     expression::{TupleExpr, TupleIndexExpr, Value},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
-    literal::UIntType,
-    punctuation::PuncKind,
-    Delimiter, Literal, Punctuation, U64Primitive,
+    Delimiter,
 };
 
 use crate::{
@@ -17,7 +15,7 @@ use crate::{
 };
 
 impl ParseExpr for TupleExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -64,53 +62,39 @@ impl ParseExpr for TupleExpr {
 }
 
 impl ParseExpr for TupleIndexExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
-        // TODO: find a way to prevent stack overflow when accessing tuple indexes (as a tuple index expression)
-        if let Some(operand) = Value::parse(parser)? {
-            if let Some(Punctuation {
-                punc_kind: PuncKind::FullStop,
-                ..
-            }) = parser.peek_next()
-            {
-                parser.next_token();
-
-                if let Some(index) = parser.peek_next::<Literal<UIntType>>() {
-                    parser.next_token();
-
-                    return Ok(Some(TupleIndexExpr {
-                        operand: Box::new(operand),
-                        index: U64Primitive::try_from(index)
-                            .expect("error converting `Literal<UIntType` to `U64Primitive`"),
-                    }));
-                }
+        // the operand is parsed before it is known whether `.` and an index follow
+        // (e.g., in `t.foo`, which is a field access), so go back to the operand if not
+        parser.try_parse(parse_tuple_index_expr)
+    }
+}
 
-                parser.log_error(ParserErrorKind::UnexpectedToken {
-                    expected: "`Literal<UIntType>`".to_string(),
-                    found: parser.current_token().unwrap_or(Token::EOF).to_string(),
-                });
-            } else {
-                return Ok(None);
-            }
-        } else {
-            return Ok(None);
+fn parse_tuple_index_expr(parser: &mut Parser) -> Result<Option<TupleIndexExpr>, Vec<Diagnostic>> {
+    if let Some(operand) = Value::parse(parser)? {
+        // chained tuple indexes (e.g., `t.0.1`) nest, with the last index outermost
+        if let Value::TupleIndexExpr(tie) = utils::get_tuple_index_chain(parser, operand)? {
+            return Ok(Some(tie));
         }
-
-        Err(parser.errors())
     }
+
+    Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
 
+    use feo_ast::expression::Expression;
+    use feo_types::{source_map::SourceMap, span::Spanned};
+
     use crate::test_utils;
 
     use super::*;
 
     #[test]
-    fn parse_tuple_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(1, "a", x)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -121,14 +105,85 @@ mod tests {
     }
 
     #[test]
-    fn parse_tuple_index_expr() -> Result<(), Vec<CompilerError>> {
-        let source_code = r#"foo.0"#;
+    fn parse_tuple_index_expr() -> Result<(), Vec<Diagnostic>> {
+        let mut source_map = SourceMap::new();
+
+        for source_code in ["t.0", "t.0.1", "self.0.2"] {
+            let mut parser =
+                test_utils::get_parser_with_source_map(&mut source_map, source_code, false)?;
+
+            let tuple_index_expr = match Expression::parse(&mut parser)? {
+                Some(Expression::TupleIndexExpr(tie)) => tie,
+                e => panic!("expected a tuple index expression, found {:?}", e),
+            };
+
+            assert_eq!(tuple_index_expr.span().as_str(&source_map), source_code);
+            assert!(parser.errors().is_empty());
+
+            // chained tuple indexes nest, with the last index outermost
+            if let Value::TupleIndexExpr(inner) = *tuple_index_expr.operand {
+                assert_eq!(
+                    inner.span().as_str(&source_map),
+                    &source_code[..source_code.len() - 2]
+                )
+            }
+        }
 
-        let mut parser = test_utils::get_parser(source_code, false)?;
+        Ok(())
+    }
+
+    #[test]
+    fn parse_tuple_index_chain() -> Result<(), Vec<Diagnostic>> {
+        let mut source_map = SourceMap::new();
+
+        let mut parser =
+            test_utils::get_parser_with_source_map(&mut source_map, "x.1.0.method()", false)?;
+
+        match Expression::parse(&mut parser)? {
+            Some(Expression::MethodCallExpr(mc)) => match *mc.receiver {
+                Value::TupleIndexExpr(receiver) => {
+                    assert_eq!(receiver.span().as_str(&source_map), "x.1.0")
+                }
+                _ => panic!("expected a tuple index expression receiver"),
+            },
+            e => panic!("expected a method call expression, found {:?}", e),
+        }
+
+        let mut parser =
+            test_utils::get_parser_with_source_map(&mut source_map, "x.0.name", false)?;
+
+        match Expression::parse(&mut parser)? {
+            Some(Expression::FieldAccessExpr(fa)) => match *fa.container_operand {
+                Value::TupleIndexExpr(container) => {
+                    assert_eq!(container.span().as_str(&source_map), "x.0")
+                }
+                _ => panic!("expected a tuple index expression container"),
+            },
+            e => panic!("expected a field access expression, found {:?}", e),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_field_access_after_tuple_index_attempt() -> Result<(), Vec<Diagnostic>> {
+        let mut source_map = SourceMap::new();
+
+        let mut parser = test_utils::get_parser_with_source_map(&mut source_map, "t.foo", false)?;
+
+        // not a tuple index, so nothing is logged before the field access is parsed
+        assert!(TupleIndexExpr::parse(&mut parser)?.is_none());
+        assert!(parser.errors().is_empty());
+
+        match Expression::parse(&mut parser)? {
+            Some(Expression::FieldAccessExpr(fa)) => {
+                assert_eq!(fa.span().as_str(&source_map), "t.foo")
+            }
+            e => panic!("expected a field access expression, found {:?}", e),
+        }
 
-        let tuple_index_expr =
-            TupleIndexExpr::parse(&mut parser).expect("unable to parse tuple index expression");
+        assert!(parser.errors().is_empty(), "{:#?}", parser.errors());
 
-        Ok(println!("{:#?}", tuple_index_expr))
+        Ok(())
     }
 }
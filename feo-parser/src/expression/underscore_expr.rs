@@ -1,11 +1,11 @@
 use feo_ast::expression::UnderscoreExpr;
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::Identifier;
 
 use crate::{parse::ParseExpr, parser::Parser};
 
 impl ParseExpr for UnderscoreExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -25,7 +25,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_underscore_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_underscore_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"_"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
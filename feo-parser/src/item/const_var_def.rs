@@ -4,7 +4,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Identifier, Keyword, Punctuation};
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 };
 
 impl ParseItem for ConstVarDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -132,7 +132,7 @@ impl ParseItem for ConstVarDef {
 }
 
 impl ParseItem for StaticVarDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -269,7 +269,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_constant_var_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_constant_var_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"const foo: u64 = 2;"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -283,7 +283,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_static_var_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_static_var_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"pub static mut foo: u64 = 2;"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -5,7 +5,7 @@ use feo_ast::{
     },
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -20,7 +20,7 @@ use crate::{
 };
 
 impl ParseTerm for EnumVariant {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -68,7 +68,7 @@ impl ParseTerm for EnumVariant {
 }
 
 impl ParseTerm for EnumVariantType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -83,7 +83,7 @@ impl ParseTerm for EnumVariantType {
 }
 
 impl ParseTerm for EnumVariantStruct {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -130,7 +130,7 @@ impl ParseTerm for EnumVariantStruct {
 }
 
 impl ParseTerm for EnumVariantTuple {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -177,7 +177,7 @@ impl ParseTerm for EnumVariantTuple {
 }
 
 impl ParseItem for EnumDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -264,7 +264,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_enum_variant() -> Result<(), Vec<CompilerError>> {
+    fn parse_enum_variant() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"Foo(u64)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -275,7 +275,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_enum_variant_struct() -> Result<(), Vec<CompilerError>> {
+    fn parse_enum_variant_struct() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"{ bar: u64 }"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -287,7 +287,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_enum_variant_tuple() -> Result<(), Vec<CompilerError>> {
+    fn parse_enum_variant_tuple() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(u64, bool)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -299,7 +299,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_enum_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_enum_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         enum Foo {
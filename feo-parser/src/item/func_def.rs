@@ -5,7 +5,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -21,7 +21,7 @@ use crate::{
 };
 
 impl ParseTerm for FuncOrMethodParam {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -36,7 +36,7 @@ impl ParseTerm for FuncOrMethodParam {
 }
 
 impl ParseTerm for SelfParam {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -99,7 +99,7 @@ impl ParseTerm for SelfParam {
 }
 
 impl ParseTerm for FuncParam {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -141,7 +141,7 @@ impl ParseTerm for FuncParam {
 }
 
 impl ParseItem for FuncSig {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -253,7 +253,7 @@ impl ParseItem for FuncSig {
 }
 
 impl ParseItem for FuncWithBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -289,7 +289,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_self_param() -> Result<(), Vec<CompilerError>> {
+    fn parse_self_param() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"&mut self: u64"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -300,7 +300,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_func_param() -> Result<(), Vec<CompilerError>> {
+    fn parse_func_param() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"foo: u64"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -312,7 +312,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_func_sig() -> Result<(), Vec<CompilerError>> {
+    fn parse_func_sig() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         pub func foo(bar: bool, baz: char) -> u64
@@ -326,7 +326,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_func_with_block() -> Result<(), Vec<CompilerError>> {
+    fn parse_func_with_block() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         pub func foo(bar: bool, baz: char) -> u64 {
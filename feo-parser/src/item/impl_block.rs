@@ -7,7 +7,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -22,7 +22,7 @@ use crate::{
 };
 
 impl ParseItem for InherentImplItem {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -37,7 +37,7 @@ impl ParseItem for InherentImplItem {
 }
 
 impl ParseItem for InherentImplBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -123,7 +123,7 @@ impl ParseItem for InherentImplBlock {
 }
 
 impl ParseItem for TraitImplItem {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -140,7 +140,7 @@ impl ParseItem for TraitImplItem {
 }
 
 impl ParseItem for TraitImplBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -258,7 +258,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_inherent_impl_block() -> Result<(), Vec<CompilerError>> {
+    fn parse_inherent_impl_block() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         impl SomeObject {
             #![unsafe]
@@ -3,7 +3,7 @@ use feo_ast::{
     path::SimplePath,
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Keyword, Punctuation};
 
 use crate::{
@@ -14,7 +14,7 @@ use crate::{
 };
 
 impl ParseTerm for ImportTree {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -35,7 +35,7 @@ impl ParseTerm for ImportTree {
 }
 
 impl ParseTerm for PathWildcard {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -65,7 +65,7 @@ impl ParseTerm for PathWildcard {
 }
 
 impl ParseTerm for PathSubset {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -80,7 +80,7 @@ impl ParseTerm for PathSubset {
 }
 
 impl ParseTerm for PathRecursive {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -95,7 +95,7 @@ impl ParseTerm for PathRecursive {
 }
 
 impl ParseItem for ImportDecl {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -161,7 +161,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_path_wildcard() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_wildcard() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"package::some_module::*"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -173,7 +173,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_path_subset() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_subset() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         some_module::{
             SomeObject, self, some_function, SOME_CONSTANT
@@ -187,7 +187,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_path_recursive() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_recursive() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         some_module::{ 
             SomeObject,
@@ -207,7 +207,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_import_decl() -> Result<(), Vec<CompilerError>> {
+    fn parse_import_decl() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[foo]
         pub import some_module::{ 
@@ -2,7 +2,7 @@ use feo_ast::{
     item::{ModuleWithBlock, ModuleWithoutBlock},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -18,7 +18,7 @@ use crate::{
 };
 
 impl ParseItem for ModuleWithoutBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -79,7 +79,7 @@ impl ParseItem for ModuleWithoutBlock {
 }
 
 impl ParseItem for ModuleWithBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -166,7 +166,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_module_without_body() -> Result<(), Vec<CompilerError>> {
+    fn parse_module_without_body() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         pub module some_mod;"#;
@@ -180,7 +180,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_module_with_body() -> Result<(), Vec<CompilerError>> {
+    fn parse_module_with_body() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         module some_mod {
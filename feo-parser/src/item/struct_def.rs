@@ -3,7 +3,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -19,7 +19,7 @@ use crate::{
 };
 
 impl ParseTerm for StructDefField {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -62,7 +62,7 @@ impl ParseTerm for StructDefField {
 }
 
 impl ParseItem for StructDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -142,7 +142,7 @@ impl ParseItem for StructDef {
 }
 
 impl ParseTerm for TupleStructDefField {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -163,7 +163,7 @@ impl ParseTerm for TupleStructDefField {
 }
 
 impl ParseTerm for TupleStructDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -263,7 +263,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_struct_def_field() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_def_field() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[foo]
         pub bar: u64
@@ -278,7 +278,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_tuple_struct_def_field() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_struct_def_field() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"#[foo] pub u64"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -290,7 +290,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_struct_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         struct Foo {
@@ -305,7 +305,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_tuple_struct_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_struct_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         struct Foo(pub u64, bool);
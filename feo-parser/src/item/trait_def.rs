@@ -2,7 +2,7 @@ use feo_ast::{
     item::{ConstVarDef, FuncSig, FuncWithBlock, TraitDef, TraitDefItem, TypeDef},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -17,7 +17,7 @@ use crate::{
 };
 
 impl ParseItem for TraitDefItem {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -36,7 +36,7 @@ impl ParseItem for TraitDefItem {
 }
 
 impl ParseItem for TraitDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -129,7 +129,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_trait_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_trait_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
             #[abstract]
             pub trait foo 
@@ -1,5 +1,5 @@
 use feo_ast::{item::TypeDef, token::Token, Type};
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Identifier, Keyword, Punctuation};
 
 use crate::{
@@ -10,7 +10,7 @@ use crate::{
 };
 
 impl ParseItem for TypeDef {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -99,7 +99,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_type_alias_def() -> Result<(), Vec<CompilerError>> {
+    fn parse_type_alias_def() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         #[abstract]
         pub type Foo = Bar;
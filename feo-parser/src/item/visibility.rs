@@ -2,7 +2,7 @@ use feo_ast::{
     item::{PubPackageVisibility, VisibilityKind},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -16,7 +16,7 @@ use crate::{
 };
 
 impl ParseTerm for VisibilityKind {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -52,7 +52,7 @@ impl ParseTerm for VisibilityKind {
 }
 
 impl ParseTerm for PubPackageVisibility {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -128,7 +128,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_visibility_kind() -> Result<(), Vec<CompilerError>> {
+    fn parse_visibility_kind() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"pub(package)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -1,67 +1,221 @@
-use std::{iter::Peekable, str::Chars, sync::Arc};
+use std::{iter::Peekable, mem, str::CharIndices, sync::Arc};
 
 use feo_ast::{
-    token::{Token, TokenStream},
+    token::{LosslessTokenStream, Token, TokenStream, Trivia, TriviaKind, TriviaToken},
     tokenize::Tokenize,
 };
 
 use feo_error::{
+    diagnostic::Diagnostic,
     error::CompilerError,
-    handler::{ErrorEmitted, Handler},
+    handler::{ErrorEmitted, Handler, SharedHandler},
     lex_error::{LexError, LexErrorKind},
+    suggestion::{Applicability, Suggestion},
+    warning::{CompilerWarning, WarningKind},
 };
 
 use feo_types::{
-    delimiter, identifier,
+    delimiter::DelimOrientation,
+    identifier,
     literal::{FloatType, IntType, Literal, UIntType},
-    punctuation,
-    span::Position,
+    punctuation::{self, PuncKind},
+    source_map::{SourceId, SourceMap, TextEdit},
+    span::{Position, Span, Spanned},
     Comment, Delimiter, DocComment, Identifier, Keyword, Punctuation, U256,
 };
 
-/// Struct that stores an input string and the current character index, and handles errors
+/// Struct that stores an input string and the current byte offset, and handles errors
 pub struct Lexer<'a> {
+    src_id: SourceId,
     input: &'a str,
-    pos: usize,
-    peekable_chars: Peekable<Chars<'a>>,
-    handler: Handler,
+    pos: usize, // byte offset of the current char (always on a UTF-8 char boundary)
+    peekable_chars: Peekable<CharIndices<'a>>,
+    open_delimiters: Vec<Delimiter>, // stack of delimiters that have not been closed yet
+    after_full_stop: bool,           // whether the last token was a `.` (for tuple indexes)
+    handler: SharedHandler,
 }
 
 impl<'a> Lexer<'a> {
-    pub fn new(input: &'a str, handler: Handler) -> Self {
+    /// Create a lexer over the file `src_id` in `source_map`.
+    /// Errors are logged to `handler` (a `Handler`, or a `SharedHandler` that a parser
+    /// logs to as well).
+    pub fn new(
+        source_map: &'a SourceMap,
+        src_id: SourceId,
+        handler: impl Into<SharedHandler>,
+    ) -> Self {
+        let input = source_map.source(src_id);
+
         Self {
+            src_id,
             input,
             pos: 0,
-            peekable_chars: input.chars().peekable(),
-            handler,
+            peekable_chars: input.char_indices().peekable(),
+            open_delimiters: Vec::new(),
+            after_full_stop: false,
+            handler: handler.into(),
         }
     }
 
-    pub fn errors(&self) -> Vec<CompilerError> {
-        self.handler.clone().get_inner().0
+    pub fn errors(&self) -> Vec<Diagnostic> {
+        self.handler.errors()
+    }
+
+    /// Return the `Handler` that errors are logged to (e.g., to render them).
+    pub fn handler(&self) -> &Handler {
+        &self.handler
     }
 
     /// Main lexer function
-    /// Returns a stream of tokens, parsed and tokenized from some input source code
-    pub fn lex(&mut self) -> Result<TokenStream, ErrorEmitted> {
-        let mut tokens: Vec<Option<Token>> = Vec::new();
+    /// Returns a stream of tokens, parsed and tokenized from some input source code.
+    /// Lexical errors are logged to the `Handler` and the offending input is replaced by a
+    /// `Token::Error`, so that every error in the input is reported in a single pass
+    /// (unless the `Handler`'s error limit is reached, in which case lexing stops there).
+    /// To lex the input lazily, one token at a time, use the lexer as an `Iterator` instead.
+    pub fn lex(&mut self) -> TokenStream {
+        let tokens = self.by_ref().collect::<Vec<Token>>();
+
+        TokenStream::new(self.src_id, tokens, 0, self.pos)
+    }
+
+    /// Lex the input without losing any of it: the whitespace and newlines around each token
+    /// are kept as its leading and trailing trivia, so that the input can be rebuilt byte for
+    /// byte from the returned stream. Errors are handled in the same way as in `lex()`.
+    pub fn lex_lossless(&mut self) -> LosslessTokenStream {
+        let mut tokens: Vec<TriviaToken> = Vec::new();
+        let mut trivia_start = self.pos;
+
+        while let Some(token) = self.next() {
+            // only whitespace is skipped before a token, so its text starts after that
+            let text_start = self.pos - self.input[trivia_start..self.pos].trim_start().len();
+
+            let leading_trivia = self.take_trivia(&mut tokens, trivia_start, text_start);
+
+            tokens.push(TriviaToken {
+                leading_trivia,
+                token,
+                text: Span::new(self.src_id, text_start, self.pos),
+                trailing_trivia: Vec::new(),
+            });
+
+            trivia_start = self.pos;
+        }
+
+        let eof_trivia = self.take_trivia(&mut tokens, trivia_start, self.pos);
+
+        LosslessTokenStream::new(self.src_id, tokens, eof_trivia, 0, self.pos)
+    }
+
+    /// Lex a file after `edit` has been made to it, reusing the tokens in its `previous` stream
+    /// that the edit cannot have changed. The lexer must have been created over the edited file
+    /// (i.e., the `SourceId` returned by `SourceMap::edit_file()`) and not have lexed anything yet.
+    /// Only the input from the last safe token boundary before the edit is re-scanned, up to the
+    /// point where the new tokens line up with the old ones again (so an edit that opens a block
+    /// comment or a string re-scans as far as the comment or string now reaches), and the
+    /// tokens after that are reused with their spans shifted.
+    /// Errors are logged for the re-scanned input, and delimiters are matched across the whole
+    /// stream; errors in reused input are only marked by its `Token::Error`s.
+    /// Only the spans of the `previous` tokens are read (not the text that they point to), so the
+    /// edit can replace the previous version of the file in the `SourceMap`.
+    pub fn relex(&mut self, previous: &TokenStream, edit: TextEdit) -> TokenStream {
+        let src_id = self.src_id;
+
+        let old_tokens = previous.tokens();
+
+        let edit_end = edit.start + edit.new_text.len(); // end of the edit in the new input
+        let delta = edit_end as isize - edit.end as isize;
+
+        // the first token that may have changed is the first that ends at or after the start of
+        // the edit, unless it directly follows other tokens (with no whitespace in between),
+        // as the lexer can look ahead (past the end of a token) as far as the next whitespace
+        // to decide where a token ends, e.g., for `r##"` or `1.5`
+        let mut first = old_tokens
+            .iter()
+            .position(|t| t.span().end() >= edit.start)
+            .unwrap_or(old_tokens.len());
+
+        while first > 0
+            && first < old_tokens.len()
+            && old_tokens[first - 1].span().end() == old_tokens[first].span().start()
+        {
+            first -= 1;
+        }
+
+        let mut tokens = old_tokens[..first]
+            .iter()
+            .map(|t| t.shift(src_id, 0))
+            .collect::<Vec<Token>>();
+
+        // restart right after the last reused token, in the state that it left the lexer in
+        if let Some(last) = tokens.last() {
+            self.seek(last.span().end());
+            self.after_full_stop = matches!(
+                last,
+                Token::Punc(Punctuation {
+                    punc_kind: PuncKind::FullStop,
+                    ..
+                })
+            );
+        }
+
+        let mut old_idx = first; // index of the old token that a new token may line up with
+
+        while let Some(token) = self.scan_token() {
+            let span = token.span();
+
+            // past the edit, a new token that is the same as an old one at the same (shifted)
+            // position means that the rest of the input lexes the same as before
+            if span.start() >= edit_end {
+                let old_start = span.start() - edit_end + edit.end;
+
+                while old_tokens
+                    .get(old_idx)
+                    .is_some_and(|t| t.span().start() < old_start)
+                {
+                    old_idx += 1;
+                }
+
+                if old_tokens.get(old_idx).is_some_and(|t| {
+                    t.span().start() == old_start
+                        && t.span().len() == span.len()
+                        && mem::discriminant(t) == mem::discriminant(&token)
+                }) {
+                    tokens.extend(old_tokens[old_idx..].iter().map(|t| t.shift(src_id, delta)));
+                    break;
+                }
+            }
+
+            tokens.push(token);
+        }
 
-        let mut num_open_delimiters: usize = 0; // to check for unclosed delimiters
-        let mut num_closed_delimiters: usize = 0; // to check for opened delimiters
+        for t in tokens.iter() {
+            self.track_delimiters(Some(t));
+        }
+
+        self.track_delimiters(None);
+
+        TokenStream::new(src_id, tokens, 0, self.input.len())
+    }
 
+    // lex the next token, skipping whitespace (and any input that does not produce a token)
+    // return `None` at the end of the input
+    fn lex_token(&mut self) -> Option<Token> {
         while let Some(c) = self.current_char() {
             let start_pos = self.pos;
 
-            match &c {
+            let token = match &c {
                 _ if c.is_whitespace() => {
                     self.skip_whitespace();
+                    None
                 }
 
-                _ if c == '/' && self.peek_next() == Some('/') || self.peek_next() == Some('*') => {
+                _ if c == '/'
+                    && (self.peek_next() == Some('/') || self.peek_next() == Some('*')) =>
+                {
                     self.advance(); // skip first '/'
                     let mut block_comment_open = false;
 
-                    match &self.current_char() {
+                    let comment = match &self.current_char() {
                         Some('/') => {
                             self.advance(); // skip second '/'
 
@@ -70,10 +224,8 @@ impl<'a> Lexer<'a> {
                             {
                                 self.advance(); // skip third '/' or '!'
 
-                                self.skip_whitespace();
-
                                 while let Some(c) = self.current_char() {
-                                    if c == '\n' {
+                                    if self.at_newline(c) {
                                         break;
                                     }
 
@@ -85,17 +237,17 @@ impl<'a> Lexer<'a> {
                                 let doc_comment_content = Arc::new(&raw_content);
 
                                 let doc_comment = DocComment::tokenize(
-                                    &self.input,
+                                    self.src_id,
                                     &doc_comment_content,
                                     start_pos,
                                     self.pos,
-                                    &mut self.handler,
-                                )?;
+                                    &self.handler,
+                                );
 
-                                tokens.push(doc_comment);
+                                self.token_or_error(doc_comment, start_pos)
                             } else {
                                 while let Some(c) = self.current_char() {
-                                    if c == '\n' {
+                                    if self.at_newline(c) {
                                         break;
                                     }
 
@@ -107,14 +259,14 @@ impl<'a> Lexer<'a> {
                                 let comment_data = Arc::new(&raw_data);
 
                                 let comment = Comment::tokenize(
-                                    &self.input,
+                                    self.src_id,
                                     &comment_data,
                                     start_pos,
                                     self.pos,
-                                    &mut self.handler,
-                                )?;
+                                    &self.handler,
+                                );
 
-                                tokens.push(comment);
+                                self.token_or_error(comment, start_pos)
                             }
                         }
 
@@ -122,47 +274,149 @@ impl<'a> Lexer<'a> {
                             self.advance(); // skip '*'
                             block_comment_open = true;
 
+                            // `/**` and `/*!` open block doc comments, but `/**/` (empty)
+                            // and `/***` (e.g., a separator line) are regular block comments
+                            let is_doc_comment = match self.current_char() {
+                                Some('!') => true,
+                                Some('*') => !matches!(self.peek_next(), Some('*') | Some('/')),
+                                _ => false,
+                            };
+
+                            // block comments can be nested, and only end at the matching `*/`
+                            let mut depth: usize = 1;
+
                             while let Some(c) = self.current_char() {
-                                if c == '*' {
+                                if c == '/' && self.peek_next() == Some('*') {
+                                    self.advance(); // skip '/'
+                                    self.advance(); // skip '*'
+                                    depth += 1;
+                                } else if c == '*' && self.peek_next() == Some('/') {
                                     self.advance(); // skip closing '*'
                                     self.advance(); // skip closing '/'
-                                    block_comment_open = false;
-                                    break;
-                                }
+                                    depth -= 1;
 
-                                self.advance();
+                                    if depth == 0 {
+                                        block_comment_open = false;
+                                        break;
+                                    }
+                                } else {
+                                    self.advance();
+                                }
                             }
 
-                            let raw_data = self.input[start_pos..self.pos].to_string();
+                            if !block_comment_open {
+                                let raw_data = self.input[start_pos..self.pos].to_string();
 
-                            let comment_data = Arc::new(&raw_data);
+                                let comment_data = Arc::new(&raw_data);
 
-                            let comment = Comment::tokenize(
-                                &self.input,
-                                &comment_data,
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                                let comment = if is_doc_comment {
+                                    DocComment::tokenize(
+                                        self.src_id,
+                                        &comment_data,
+                                        start_pos,
+                                        self.pos,
+                                        &self.handler,
+                                    )
+                                } else {
+                                    Comment::tokenize(
+                                        self.src_id,
+                                        &comment_data,
+                                        start_pos,
+                                        self.pos,
+                                        &self.handler,
+                                    )
+                                };
 
-                            tokens.push(comment);
+                                self.token_or_error(comment, start_pos)
+                            } else {
+                                None
+                            }
                         }
 
-                        Some(_) | None => (),
-                    }
+                        Some(_) | None => None,
+                    };
 
                     if block_comment_open {
-                        return Err(self.log_error(LexErrorKind::UnclosedBlockComment));
+                        // the rest of the input has been consumed by the comment
+                        self.log_error(LexErrorKind::UnclosedBlockComment);
+                        self.error_token(start_pos)
+                    } else {
+                        comment
+                    }
+                }
+
+                // byte literals (`b'a'`, `b"abc"`, `br"abc"`) and raw strings (`r"abc"`, `r#"abc"#`)
+                'b' | 'r' if self.at_quoted_literal_prefix() => {
+                    let is_byte = c == 'b';
+
+                    if is_byte {
+                        self.advance(); // skip 'b'
+                    }
+
+                    match self.advance() {
+                        Some('r') => self.lex_raw_string(start_pos, is_byte),
+                        Some('\'') => self.lex_char(start_pos, is_byte),
+                        _ => self.lex_string(start_pos, is_byte),
                     }
                 }
 
+                // raw identifiers (`r#name`), which may have the same name as a keyword
+                'r' if self.peek_next() == Some('#')
+                    && self
+                        .peekable_chars
+                        .clone()
+                        .nth(2)
+                        .is_some_and(|(_, x)| identifier::is_identifier_start(x)) =>
+                {
+                    self.advance(); // skip 'r'
+                    self.advance(); // skip '#'
+
+                    let name_start_pos = self.pos;
+
+                    while self
+                        .current_char()
+                        .is_some_and(identifier::is_identifier_continue)
+                    {
+                        self.advance();
+                    }
+
+                    // path segment keywords cannot be used as raw identifiers
+                    if ["self", "Self", "super"].contains(&&self.input[name_start_pos..self.pos]) {
+                        self.log_error_at(LexErrorKind::InvalidRawIdentifier, start_pos);
+                        return self.error_token(start_pos);
+                    }
+
+                    let name = &self.input[name_start_pos..self.pos];
+
+                    if !identifier::is_strict_keyword(name)
+                        && !identifier::is_contextual_keyword(name)
+                    {
+                        self.handler.emit_warn(CompilerWarning::new(
+                            WarningKind::UnnecessaryRawIdentifier {
+                                name: name.to_string(),
+                            },
+                            Span::new(self.src_id, start_pos, self.pos),
+                        ));
+                    }
+
+                    let identifier = Identifier::tokenize(
+                        self.src_id,
+                        &self.input[start_pos..self.pos],
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    );
+
+                    self.token_or_error(identifier, start_pos)
+                }
+
                 // identifiers and keywords
-                'A'..='Z' | 'a'..='z' | '_' => {
+                _ if identifier::is_identifier_start(c) => {
                     let mut buf = String::new();
 
                     while let Some(c) = self.current_char() {
-                        // cannot start with, but can contain numbers (`is_ascii_alphanumeric()`)
-                        if c.is_ascii_alphanumeric() || c == '_' {
+                        // cannot start with, but can contain numbers (`XID_Continue`)
+                        if identifier::is_identifier_continue(c) {
                             buf.push(c);
                             self.advance();
                         } else {
@@ -172,529 +426,862 @@ impl<'a> Lexer<'a> {
 
                     if &buf == "true" || &buf == "false" {
                         let bool_literal = Literal::<bool>::tokenize(
-                            &self.input,
+                            self.src_id,
                             &buf,
                             start_pos, // global `start_pos`
-                            start_pos + buf.len(),
-                            &mut self.handler,
-                        )?;
-
-                        tokens.push(bool_literal);
-                        continue;
-                    }
+                            self.pos,
+                            &self.handler,
+                        );
 
-                    if identifier::is_keyword(&buf) {
+                        self.token_or_error(bool_literal, start_pos)
+                    } else if identifier::is_strict_keyword(&buf) {
                         let keyword = Keyword::tokenize(
-                            &self.input,
+                            self.src_id,
                             &buf,
                             start_pos, // global `start_pos`
-                            start_pos + buf.len(),
-                            &mut self.handler,
-                        )?;
+                            self.pos,
+                            &self.handler,
+                        );
 
-                        tokens.push(keyword)
+                        self.token_or_error(keyword, start_pos)
                     } else {
+                        // includes contextual keywords (e.g., `storage`), which the parser
+                        // recognises by their text where the grammar expects them
                         let identifier = Identifier::tokenize(
-                            &self.input,
+                            self.src_id,
                             &buf,
                             start_pos, // global `start_pos`
-                            start_pos + buf.len(),
-                            &mut self.handler,
-                        )?;
+                            self.pos,
+                            &self.handler,
+                        );
 
-                        tokens.push(identifier);
+                        self.token_or_error(identifier, start_pos)
                     }
                 }
 
-                '(' | '[' | '{' => {
-                    self.advance(); // skip opening delimiter
+                // delimiters are matched against each other in `Iterator::next()`
+                '(' | '[' | '{' | ')' | ']' | '}' => {
+                    self.advance(); // skip delimiter (advance counter for correct end pos)
 
-                    match &c {
-                        '(' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                "(",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                    let delimiter = Delimiter::tokenize(
+                        self.src_id,
+                        &c.to_string(),
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    );
 
-                            tokens.push(delimiter);
-                        }
+                    self.token_or_error(delimiter, start_pos)
+                }
 
-                        '[' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                "[",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                '"' => {
+                    // `start_pos` is global `start_pos` (above)
+                    self.advance(); // skip opening '"' (double quote)
 
-                            tokens.push(delimiter);
-                        }
+                    self.lex_string(start_pos, false)
+                }
 
-                        '{' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                "{",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                '\'' => {
+                    // `start_pos` is global `start_pos` (above), used for error tokens
+                    self.advance(); // skip opening '\'' (single quote)
 
-                            tokens.push(delimiter);
+                    self.lex_char(start_pos, false)
+                }
+
+                _ if c.is_ascii_digit()
+                    || (c == '-' && self.peek_next().is_some_and(|c| c.is_ascii_digit())) =>
+                {
+                    // `start_pos` is global `start_pos` (above), i.e., includes any '-'
+                    let is_negative = c == '-';
+
+                    if is_negative {
+                        self.advance(); // skip '-'
+                    }
+
+                    let radix = if self.current_char() == Some('0') {
+                        match self.peek_next().map(|x| x.to_ascii_lowercase()) {
+                            Some('x') => 16,
+                            Some('o') => 8,
+                            Some('b') => 2,
+                            _ => 10,
                         }
-                        _ => unreachable!(),
+                    } else {
+                        10
                     };
 
-                    num_open_delimiters += 1;
-                }
+                    if radix != 10 {
+                        self.advance(); // skip '0'
+                        self.advance(); // skip radix prefix char
+                    }
 
-                ')' | ']' | '}' => {
-                    self.advance(); // skip closing delimiter (advance counter for correct end pos)
+                    // binary and octal literals also consume (invalid) hexadecimal digits,
+                    // so that e.g., `0b102` is reported as a single malformed literal
+                    self.skip_digits(if radix == 10 { 10 } else { 16 });
 
-                    match &c {
-                        ')' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                ")",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                    let mut is_float = false;
 
-                            tokens.push(delimiter);
+                    // a number directly after a `.` is a tuple index, so `t.0.1` is lexed as
+                    // `t`, `.`, `0`, `.`, `1` rather than `t`, `.`, `0.1`
+                    if radix == 10 && !self.after_full_stop {
+                        // fractional part (not a range `1..2` or a method call `1.foo()`)
+                        if self.current_char() == Some('.')
+                            && !self
+                                .peek_next()
+                                .is_some_and(|x| x == '.' || x == '_' || x.is_alphabetic())
+                        {
+                            self.advance(); // skip '.'
+                            self.skip_digits(10);
+                            is_float = true;
                         }
 
-                        ']' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                "]",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                        // exponent (e.g., `1e18`, `2.5E-3`)
+                        if self.current_char().is_some_and(|x| x == 'e' || x == 'E') {
+                            let mut lookahead = self.peekable_chars.clone();
+                            lookahead.next(); // skip 'e'
 
-                            tokens.push(delimiter);
-                        }
+                            let sign = lookahead.next_if(|(_, x)| *x == '+' || *x == '-');
 
-                        '}' => {
-                            let delimiter = Delimiter::tokenize(
-                                &self.input,
-                                "}",
-                                start_pos,
-                                self.pos,
-                                &mut self.handler,
-                            )?;
+                            if lookahead.peek().is_some_and(|(_, x)| x.is_ascii_digit()) {
+                                self.advance(); // skip 'e'
 
-                            tokens.push(delimiter);
-                        }
+                                if let Some((_, x)) = sign {
+                                    self.advance(); // skip sign
+                                    is_float |= x == '-';
+                                }
 
-                        _ => unreachable!(), // TODO: replace with something more meaningful
-                    };
+                                self.skip_digits(10);
+                            }
+                        }
+                    }
 
-                    num_closed_delimiters += 1;
+                    // type suffix (e.g., `u8`, `i32`, `f64`, `u256`)
+                    let suffix_start = self.pos;
 
-                    if num_closed_delimiters > num_open_delimiters {
-                        return Err(self.log_error(LexErrorKind::UnexpectedCloseDelimiter));
+                    while let Some(x) = self.current_char() {
+                        if x.is_alphanumeric() || x == '_' {
+                            self.advance();
+                        } else {
+                            break;
+                        }
                     }
-                }
 
-                '"' => {
-                    // `start_pos` is global `start_pos` (above)
-                    self.advance(); // skip opening '"' (double quote)
+                    let suffix = &self.input[suffix_start..self.pos];
 
-                    let mut string_literal_open = true; // to check for unclosed quotes
+                    let data = self.input[start_pos..self.pos].to_string();
 
-                    let mut buf = String::new();
+                    let num_content = Arc::new(&data);
 
-                    while let Some(c) = self.current_char() {
-                        match c {
-                            '\\' => {
-                                self.advance(); // skip '\'
-
-                                if let Some(esc_c) = self.current_char() {
-                                    self.advance(); // return escaped char
-
-                                    match esc_c {
-                                        'n' => buf.push('\n'),
-                                        'r' => buf.push('\r'),
-                                        't' => buf.push('\t'),
-                                        '\\' => buf.push('\\'),
-                                        '0' => buf.push('\0'),
-                                        '\'' => buf.push('\''),
-                                        '\"' => buf.push('"'),
-                                        _ => {
-                                            return Err(
-                                                self.log_error(LexErrorKind::InvalidEscapeSequence)
-                                            )
-                                        }
-                                    };
-                                } else {
-                                    // escape sequence is expected, but the input has ended
-                                    return Err(
-                                        self.log_error(LexErrorKind::ExpectedEscapeSequence)
-                                    );
-                                }
-                            }
+                    let token = match suffix {
+                        "u256" => Literal::<U256>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
 
-                            '"' => {
-                                self.advance(); // skip closing '"'
+                        "u8" | "u16" | "u32" | "u64" => Literal::<UIntType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
 
-                                string_literal_open = false;
+                        "i32" | "i64" => Literal::<IntType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
 
-                                let string_literal = Literal::<String>::tokenize(
-                                    &self.input,
-                                    &buf,
-                                    start_pos,
-                                    self.pos,
-                                    &mut self.handler,
-                                )?;
+                        "f32" | "f64" => Literal::<FloatType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
 
-                                tokens.push(string_literal);
-                                break;
-                            }
+                        "" if is_float => Literal::<FloatType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
 
-                            _ => {
-                                buf.push(c);
-                                self.advance();
-                            }
+                        "" if is_negative => Literal::<IntType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
+
+                        "" => Literal::<UIntType>::tokenize(
+                            self.src_id,
+                            &num_content,
+                            start_pos,
+                            self.pos,
+                            &self.handler,
+                        ),
+
+                        _ => {
+                            Err(self.log_error_at(LexErrorKind::InvalidNumericSuffix, suffix_start))
                         }
-                    }
+                    };
 
-                    if string_literal_open {
-                        return Err(self.log_error(LexErrorKind::ExpectedClosingDoubleQuote));
-                    }
+                    self.token_or_error(token, start_pos)
                 }
 
-                '\'' => {
-                    self.advance(); // skip opening '\'' (single quote)
+                ',' | ';' => {
+                    self.advance();
 
-                    let start_pos = self.pos; // start reading input after opening quote
+                    let data = self.input[start_pos..self.pos].to_string();
 
-                    if let Some(c) = self.current_char() {
-                        match c {
-                            '\\' => {
-                                self.advance(); // skip '\'
+                    let punc_content = Arc::new(&data);
 
-                                let esc_char_literal = match self.current_char() {
-                                    Some('n') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\n",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                    let punctuation = Punctuation::tokenize(
+                        self.src_id,
+                        &punc_content,
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    );
 
-                                    Some('r') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\r",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                    self.token_or_error(punctuation, start_pos)
+                }
 
-                                    Some('t') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\t",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                '.' => {
+                    self.advance();
 
-                                    Some('\\') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\\",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                    if let Some('.') = self.current_char() {
+                        self.advance();
+                    }
 
-                                    Some('0') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\0",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                    if let Some('=') = self.current_char() {
+                        self.advance();
+                    }
 
-                                    Some('\'') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\'",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
+                    let data = self.input[start_pos..self.pos].to_string();
 
-                                    Some('"') => Literal::<char>::tokenize(
-                                        &self.input,
-                                        "\"",
-                                        start_pos,
-                                        self.pos + 1,
-                                        &mut self.handler,
-                                    )?,
-
-                                    _ => {
-                                        return Err(
-                                            self.log_error(LexErrorKind::InvalidEscapeSequence)
-                                        )?
-                                    }
-                                };
+                    let punc_content = Arc::new(&data);
 
-                                tokens.push(esc_char_literal);
-                                self.advance(); // skip second char
+                    let punctuation = Punctuation::tokenize(
+                        self.src_id,
+                        &punc_content,
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    );
 
-                                if self.current_char() != Some('\'') {
-                                    return Err(
-                                        self.log_error(LexErrorKind::ExpectedClosingSingleQuote)
-                                    );
-                                }
+                    self.token_or_error(punctuation, start_pos)
+                }
 
-                                self.advance(); // skip closing '\'' (single quote)
-                            }
+                '!'
+                | '#'..='&'
+                | '*'
+                | '+'
+                | '/'
+                | '-'
+                | ':'
+                | '<'..='@'
+                | '\\'
+                | '^'
+                | '`'
+                | '|' => {
+                    // take the longest punctuation that starts here (e.g., `>` and `-` in `a>-b`,
+                    // rather than the whole run `>-`), or a single (unrecognised) char
+                    let punc_len = punctuation::longest_match(&self.input[start_pos..])
+                        .map_or(c.len_utf8(), |(_, len)| len);
 
-                            '\'' => {
-                                return Err(self.log_error(LexErrorKind::EmptyCharLiteral));
-                            }
+                    while self.pos < start_pos + punc_len {
+                        self.advance();
+                    }
 
-                            _ => {
-                                if c == ' ' {
-                                    return Err(self.log_error(LexErrorKind::InvalidCharLiteral));
-                                }
+                    let data = self.input[start_pos..self.pos].to_string();
 
-                                self.advance(); // return next (regular) char
+                    let punc_content = Arc::new(&data);
 
-                                if self.current_char() == Some('\'') {
-                                    let char_literal = Literal::<char>::tokenize(
-                                        &self.input,
-                                        &c.to_string(),
-                                        start_pos,
-                                        self.pos,
-                                        &mut self.handler,
-                                    )?;
+                    let punctuation = Punctuation::tokenize(
+                        self.src_id,
+                        &punc_content,
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    );
 
-                                    tokens.push(char_literal);
-                                    self.advance(); // skip closing '\'' (single quote)
-                                } else {
-                                    return Err(self.log_error(LexErrorKind::InvalidCharLiteral));
-                                }
-                            }
-                        }
+                    self.token_or_error(punctuation, start_pos)
+                }
+
+                _ => {
+                    self.log_error(LexErrorKind::InvalidChar(c));
+                    self.advance(); // skip the invalid char
+                    self.error_token(start_pos)
+                }
+            };
+
+            if token.is_some() {
+                return token;
+            }
+        }
+
+        None
+    }
+
+    // lex the next token (see `lex_token()`) and keep track of whether it is a `.`,
+    // which makes a number directly after it a tuple index
+    fn scan_token(&mut self) -> Option<Token> {
+        let token = self.lex_token();
+
+        self.after_full_stop = matches!(
+            token,
+            Some(Token::Punc(Punctuation {
+                punc_kind: PuncKind::FullStop,
+                ..
+            }))
+        );
+
+        token
+    }
+
+    // update the stack of open delimiters with the next token (`None` at the end of the input),
+    // reporting unexpected and mismatched close delimiters, and (at the end of the input)
+    // every delimiter that is still open, at the position where it was opened
+    fn track_delimiters(&mut self, token: Option<&Token>) {
+        match token {
+            Some(Token::Delim(d)) => match d.delim.1 {
+                DelimOrientation::Open => self.open_delimiters.push(d.clone()),
+                DelimOrientation::Close => self.close_delimiter(d),
+            },
+
+            Some(_) => (),
+
+            None => {
+                let open_delimiters = std::mem::take(&mut self.open_delimiters);
+
+                // close delimiters for all the open delimiters (innermost first), to be
+                // inserted at the end of the input along with the first error
+                let closes = open_delimiters
+                    .iter()
+                    .rev()
+                    .map(|d| {
+                        Delimiter::new(d.delim.0.clone(), DelimOrientation::Close, d.span).as_char()
+                    })
+                    .collect::<String>();
+
+                for (i, open) in open_delimiters.into_iter().enumerate() {
+                    let diagnostic = Diagnostic::from(LexError {
+                        error_kind: LexErrorKind::UnclosedDelimiters,
+                        position: Position::new(self.src_id, open.span.start()),
+                    });
+
+                    if i == 0 {
+                        self.handler.emit_diagnostic(diagnostic.with_suggestion(
+                            Suggestion::insertion(
+                                self.src_id,
+                                self.input.len(),
+                                &closes,
+                                Applicability::MachineApplicable,
+                            ),
+                        ));
                     } else {
-                        return Err(self.log_error(LexErrorKind::ExpectedCharLiteral));
+                        self.handler.emit_diagnostic(diagnostic);
                     }
                 }
+            }
+        }
+    }
 
-                // check for hexadecimal prefix
-                _ if c == '0'
-                    && self
-                        .peek_next()
-                        .is_some_and(|x| &x.to_lowercase().to_string() == "x") =>
-                {
-                    // `start_pos` is global `start_pos` (above)
+    // split the whitespace between `start` and `end` into trivia, give the trivia up to and
+    // including the first newline to the last token (as its trailing trivia),
+    // and return the rest
+    fn take_trivia(&self, tokens: &mut [TriviaToken], start: usize, end: usize) -> Vec<Trivia> {
+        let mut trivia: Vec<Trivia> = Vec::new();
+        let mut pos = start;
 
-                    self.advance(); // skip '0'
-                    self.advance(); // skip 'x'
+        while pos < end {
+            let rest = &self.input[pos..end];
 
-                    while let Some(c) = self.current_char() {
-                        if c.is_digit(16) || c == '_' {
-                            self.advance();
-                        } else {
-                            break;
+            let (kind, len) = if rest.starts_with("\r\n") {
+                (TriviaKind::Newline, 2)
+            } else if rest.starts_with('\n') {
+                (TriviaKind::Newline, 1)
+            } else {
+                let len = rest
+                    .char_indices()
+                    .skip(1)
+                    .find(|(i, _)| rest[*i..].starts_with('\n') || rest[*i..].starts_with("\r\n"))
+                    .map_or(rest.len(), |(i, _)| i);
+
+                (TriviaKind::Whitespace, len)
+            };
+
+            trivia.push(Trivia {
+                kind,
+                span: Span::new(self.src_id, pos, pos + len),
+            });
+
+            pos += len;
+        }
+
+        if let Some(last) = tokens.last_mut() {
+            let num_trailing = trivia
+                .iter()
+                .position(|t| t.kind == TriviaKind::Newline)
+                .map_or(trivia.len(), |i| i + 1);
+
+            last.trailing_trivia = trivia.drain(..num_trailing).collect();
+        }
+
+        trivia
+    }
+
+    // replace a token that could not be tokenized (its error has already been emitted)
+    // with a `Token::Error` spanning the input from `start` to the current position
+    fn token_or_error(
+        &self,
+        token: Result<Option<Token>, ErrorEmitted>,
+        start: usize,
+    ) -> Option<Token> {
+        match token {
+            Ok(t) => t,
+            Err(_) => self.error_token(start),
+        }
+    }
+
+    // return a `Token::Error` spanning the input from `start` to the current position,
+    // used in place of input that could not be lexed so that lexing can carry on
+    fn error_token(&self, start: usize) -> Option<Token> {
+        Some(Token::Error(
+            Span::new(self.src_id, start, self.pos),
+            self.input[start..self.pos].to_string(),
+        ))
+    }
+
+    // match a close delimiter against the innermost open delimiter
+    // on a mismatch, the error points at both the open and the offending close delimiter;
+    // if the close delimiter matches one further out, the delimiters opened in between are
+    // closed with it, otherwise it is treated as closing the innermost open delimiter
+    fn close_delimiter(&mut self, close: &Delimiter) {
+        let open = match self.open_delimiters.last() {
+            Some(d) => d.clone(),
+            None => {
+                self.log_error_at(LexErrorKind::UnexpectedCloseDelimiter, close.span.start());
+                return;
+            }
+        };
+
+        if open.delim.0 == close.delim.0 {
+            self.open_delimiters.pop();
+            return;
+        }
+
+        self.log_error_at(
+            LexErrorKind::MismatchedDelimiters {
+                open: open.clone().as_char(),
+                open_position: open.span.start_pos(),
+                close: close.clone().as_char(),
+            },
+            close.span.start(),
+        );
+
+        match self
+            .open_delimiters
+            .iter()
+            .rposition(|d| d.delim.0 == close.delim.0)
+        {
+            Some(idx) => self.open_delimiters.truncate(idx),
+            None => {
+                self.open_delimiters.pop();
+            }
+        }
+    }
+
+    // lex the rest of a (byte) string literal, after its opening '"'
+    fn lex_string(&mut self, start_pos: usize, is_byte: bool) -> Option<Token> {
+        let mut string_literal_open = true; // to check for unclosed quotes
+        let mut is_valid = true; // to check for invalid escape sequences
+
+        // byte strings store one char per byte
+        let mut buf = String::new();
+
+        while let Some(c) = self.current_char() {
+            match c {
+                '\\' => match self.lex_escape(is_byte) {
+                    Some(esc_c) => buf.push(esc_c),
+                    None => {
+                        // keep reading up to the closing quote
+                        is_valid = false;
+
+                        if self.current_char().is_none() {
+                            // the input ended mid escape sequence (already reported)
+                            string_literal_open = false;
                         }
                     }
+                },
 
-                    let data = self.input[start_pos..self.pos].to_string();
+                '"' => {
+                    self.advance(); // skip closing '"'
 
-                    let num_content = Arc::new(&data);
+                    string_literal_open = false;
+                    break;
+                }
 
-                    let u256_literal = Literal::<U256>::tokenize(
-                        &self.input,
-                        &num_content,
-                        start_pos,
-                        self.pos,
-                        &mut self.handler,
-                    )?;
+                _ => {
+                    if is_byte && !c.is_ascii() {
+                        self.log_error(LexErrorKind::NonAsciiByteLiteral);
+                        is_valid = false;
+                    }
 
-                    tokens.push(u256_literal);
+                    buf.push(c);
+                    self.advance();
                 }
+            }
+        }
 
-                _ if c.is_digit(10)
-                    || (c == '-' && self.peek_next().is_some_and(|c| c.is_digit(10))) =>
-                {
-                    let mut is_negative = false;
+        if string_literal_open {
+            self.log_error(LexErrorKind::ExpectedClosingDoubleQuote);
+            return self.error_token(start_pos);
+        }
 
-                    if c == '-' && self.peek_next().is_some_and(|c| c.is_digit(10)) {
-                        is_negative = true;
-                        self.advance(); // skip '-'
-                    }
+        if !is_valid {
+            return self.error_token(start_pos);
+        }
 
-                    // go back and read from previous char ('-') if neg, else read from current pos
-                    let start_pos = if is_negative { self.pos - 1 } else { self.pos };
+        let string_literal = if is_byte {
+            Literal::<Vec<u8>>::tokenize(self.src_id, &buf, start_pos, self.pos, &self.handler)
+        } else {
+            Literal::<String>::tokenize(self.src_id, &buf, start_pos, self.pos, &self.handler)
+        };
 
-                    let mut is_float = false;
+        self.token_or_error(string_literal, start_pos)
+    }
 
-                    while let Some(c) = self.current_char() {
-                        if c.is_digit(10) || c == '_' {
-                            self.advance();
-                        } else if c == '.' && !is_float && self.peek_next() != Some('.') {
-                            self.advance();
-                            is_float = true;
-                        } else {
-                            break;
-                        }
-                    }
+    // lex the rest of a raw (byte) string literal, after its `r` (e.g., `#"abc"#`)
+    // raw strings have no escape sequences and end at a '"' followed by the same number of '#'
+    // as there were before the opening '"'
+    fn lex_raw_string(&mut self, start_pos: usize, is_byte: bool) -> Option<Token> {
+        let mut num_hashes: usize = 0;
 
-                    let data = self.input[start_pos..self.pos].to_string();
+        while self.current_char() == Some('#') {
+            num_hashes += 1;
+            self.advance(); // skip '#'
+        }
 
-                    let num_content = Arc::new(&data);
+        self.advance(); // skip opening '"'
 
-                    if is_float {
-                        let float_literal = Literal::<FloatType>::tokenize(
-                            &self.input,
-                            &num_content,
-                            start_pos,
-                            self.pos,
-                            &mut self.handler,
-                        )?;
+        let content_start_pos = self.pos;
 
-                        tokens.push(float_literal);
-                        continue;
-                    }
+        let mut is_valid = true;
 
-                    if is_negative {
-                        let int_literal = Literal::<IntType>::tokenize(
-                            &self.input,
-                            &num_content,
-                            start_pos,
-                            self.pos,
-                            &mut self.handler,
-                        )?;
+        while let Some(c) = self.current_char() {
+            if c == '"' {
+                let content_end_pos = self.pos;
 
-                        tokens.push(int_literal);
-                    } else {
-                        let uint_literal = Literal::<UIntType>::tokenize(
-                            &self.input,
-                            &num_content,
-                            start_pos,
-                            self.pos,
-                            &mut self.handler,
-                        )?;
+                self.advance(); // skip '"'
 
-                        tokens.push(uint_literal);
-                    }
+                let mut closing_hashes: usize = 0;
+
+                while closing_hashes < num_hashes && self.current_char() == Some('#') {
+                    closing_hashes += 1;
+                    self.advance(); // skip '#'
                 }
 
-                ',' | ';' => {
-                    self.advance();
+                if closing_hashes < num_hashes {
+                    // part of the content, e.g., `r#"a "quote" b"#`
+                    continue;
+                }
 
-                    let data = self.input[start_pos..self.pos].to_string();
+                if !is_valid {
+                    return self.error_token(start_pos);
+                }
 
-                    let punc_content = Arc::new(&data);
+                let content = &self.input[content_start_pos..content_end_pos];
 
-                    let punctuation = Punctuation::tokenize(
-                        &self.input,
-                        &punc_content,
+                let raw_string_literal = if is_byte {
+                    Literal::<Vec<u8>>::tokenize(
+                        self.src_id,
+                        content,
                         start_pos,
                         self.pos,
-                        &mut self.handler,
-                    )?;
+                        &self.handler,
+                    )
+                } else {
+                    Literal::<String>::tokenize(
+                        self.src_id,
+                        content,
+                        start_pos,
+                        self.pos,
+                        &self.handler,
+                    )
+                };
+
+                return self.token_or_error(raw_string_literal, start_pos);
+            }
+
+            if is_byte && !c.is_ascii() {
+                self.log_error(LexErrorKind::NonAsciiByteLiteral);
+                is_valid = false;
+            }
+
+            self.advance();
+        }
+
+        self.log_error(LexErrorKind::ExpectedClosingDoubleQuote);
+        self.error_token(start_pos)
+    }
+
+    // lex the rest of a (byte) char literal, after its opening '\''
+    fn lex_char(&mut self, start_pos: usize, is_byte: bool) -> Option<Token> {
+        let char_start_pos = self.pos; // start reading input after opening quote
 
-                    tokens.push(punctuation);
+        let (c, is_escaped) = match self.current_char() {
+            Some('\\') => match self.lex_escape(is_byte) {
+                Some(esc_c) => (esc_c, true),
+                None => {
+                    self.skip_to_closing_quote();
+                    return self.error_token(start_pos);
                 }
+            },
 
-                '.' => {
-                    self.advance();
+            Some('\'') => {
+                self.log_error(LexErrorKind::EmptyCharLiteral);
+                self.advance(); // skip closing '\'' (single quote)
+                return self.error_token(start_pos);
+            }
 
-                    if let Some('.') = self.current_char() {
-                        self.advance();
-                    }
+            Some(' ') => {
+                self.log_error(LexErrorKind::InvalidCharLiteral);
+                self.skip_to_closing_quote();
+                return self.error_token(start_pos);
+            }
 
-                    if let Some('=') = self.current_char() {
-                        self.advance();
+            Some(c) => {
+                self.advance(); // skip (regular) char
+                (c, false)
+            }
+
+            None => {
+                self.log_error(LexErrorKind::ExpectedCharLiteral);
+                return self.error_token(start_pos);
+            }
+        };
+
+        if self.current_char() != Some('\'') {
+            if is_escaped {
+                self.log_error(LexErrorKind::ExpectedClosingSingleQuote);
+            } else {
+                self.log_error(LexErrorKind::InvalidCharLiteral);
+            }
+
+            self.skip_to_closing_quote();
+            return self.error_token(start_pos);
+        }
+
+        if is_byte && !is_escaped && !c.is_ascii() {
+            self.log_error_at(LexErrorKind::NonAsciiByteLiteral, char_start_pos);
+            self.advance(); // skip closing '\'' (single quote)
+            return self.error_token(start_pos);
+        }
+
+        self.advance(); // skip closing '\'' (single quote)
+
+        // like a string literal, the literal's span includes its quotes (and any `b` prefix)
+        let char_literal = if is_byte {
+            Literal::<u8>::tokenize(
+                self.src_id,
+                &c.to_string(),
+                start_pos,
+                self.pos,
+                &self.handler,
+            )
+        } else {
+            Literal::<char>::tokenize(
+                self.src_id,
+                &c.to_string(),
+                start_pos,
+                self.pos,
+                &self.handler,
+            )
+        };
+
+        self.token_or_error(char_literal, start_pos)
+    }
+
+    // lex an escape sequence starting at the current '\' and return the escaped char
+    // (in byte literals, `\xHH` may encode any byte, and is returned as the char `U+00HH`)
+    // on error, the error is logged and the lexer is left after the malformed escape sequence
+    fn lex_escape(&mut self, is_byte: bool) -> Option<char> {
+        let esc_start_pos = self.pos;
+
+        self.advance(); // skip '\'
+
+        let esc_c = match self.advance() {
+            Some(esc_c) => esc_c,
+            None => {
+                // escape sequence is expected, but the input has ended
+                self.log_error(LexErrorKind::ExpectedEscapeSequence);
+                return None;
+            }
+        };
+
+        let escaped = match esc_c {
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            '\\' => Some('\\'),
+            '0' => Some('\0'),
+            '\'' => Some('\''),
+            '"' => Some('"'),
+
+            // `\x7F` (up to `\xFF` in byte literals)
+            'x' => {
+                let mut value: u32 = 0;
+                let mut num_digits = 0;
+
+                while num_digits < 2 {
+                    match self.current_char().and_then(|d| d.to_digit(16)) {
+                        Some(d) => {
+                            value = value * 16 + d;
+                            num_digits += 1;
+                            self.advance();
+                        }
+                        None => break,
                     }
+                }
 
-                    let data = self.input[start_pos..self.pos].to_string();
+                if num_digits == 2 && (is_byte || value <= 0x7F) {
+                    char::from_u32(value)
+                } else {
+                    None
+                }
+            }
 
-                    let punc_content = Arc::new(&data);
+            // `\u{1F600}` (not allowed in byte literals)
+            'u' if !is_byte && self.current_char() == Some('{') => {
+                self.advance(); // skip '{'
 
-                    let punctuation = Punctuation::tokenize(
-                        &self.input,
-                        &punc_content,
-                        start_pos,
-                        self.pos,
-                        &mut self.handler,
-                    )?;
+                let digits_start_pos = self.pos;
 
-                    tokens.push(punctuation);
+                while self.current_char().is_some_and(|d| d.is_ascii_hexdigit()) {
+                    self.advance();
                 }
 
-                '!'
-                | '#'..='&'
-                | '*'
-                | '+'
-                | '/'
-                | '-'
-                | ':'
-                | '<'..='@'
-                | '\\'
-                | '^'
-                | '`'
-                | '|' => {
-                    while let Some(c) = self.current_char() {
-                        if c.is_ascii_punctuation()
-                            && !delimiter::is_delimiter(c)
-                            && !punctuation::is_quote(c)
-                            && !punctuation::is_separator(c)
-                        {
-                            self.advance();
-                        } else {
-                            break;
-                        }
+                let digits = &self.input[digits_start_pos..self.pos];
+
+                if self.current_char() == Some('}') {
+                    self.advance(); // skip '}'
+
+                    if (1..=6).contains(&digits.len()) {
+                        u32::from_str_radix(digits, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                    } else {
+                        None
                     }
+                } else {
+                    None
+                }
+            }
 
-                    let data = self.input[start_pos..self.pos].to_string();
+            _ => None,
+        };
 
-                    let punc_content = Arc::new(&data);
+        if escaped.is_none() {
+            self.log_error_at(LexErrorKind::InvalidEscapeSequence, esc_start_pos);
+        }
 
-                    let punctuation = Punctuation::tokenize(
-                        &self.input,
-                        &punc_content,
-                        start_pos,
-                        self.pos,
-                        &mut self.handler,
-                    )?;
+        escaped
+    }
 
-                    tokens.push(punctuation);
-                }
+    // check whether the input at the current position starts a byte literal or a raw string
+    // (i.e., `b'`, `b"`, `br"` or `r"`, with any number of '#' before the '"' of a raw string),
+    // as opposed to an identifier starting with `b` or `r` (including `r#ident`)
+    fn at_quoted_literal_prefix(&self) -> bool {
+        let mut chars = self.peekable_chars.clone().map(|(_, c)| c);
+
+        let mut c = chars.next();
 
-                _ if !self.peek_next().is_some() => tokens.push(Some(Token::EOF)),
+        if c == Some('b') {
+            c = chars.next();
 
-                _ => return Err(self.log_error(LexErrorKind::InvalidChar(c))),
+            if c == Some('\'') || c == Some('"') {
+                return true;
             }
         }
 
-        if num_closed_delimiters != num_open_delimiters {
-            return Err(self.log_error(LexErrorKind::UnclosedDelimiters));
+        if c != Some('r') {
+            return false;
+        }
+
+        c = chars.next();
+
+        while c == Some('#') {
+            c = chars.next();
         }
 
-        let stream = TokenStream::new(&self.input, tokens, 0, self.pos);
+        c == Some('"')
+    }
+
+    // resynchronise after a malformed char literal by skipping past its closing quote
+    // (if there is one on the same line)
+    fn skip_to_closing_quote(&mut self) {
+        while let Some(c) = self.current_char() {
+            if c == '\'' {
+                self.advance();
+                break;
+            }
+
+            if c == '\n' {
+                break;
+            }
 
-        Ok(stream)
+            self.advance();
+        }
     }
 
     // progress through the source code so that the lexer can continue to process chars
     fn advance(&mut self) -> Option<char> {
-        // update the lexer's position or other internal state if needed
-        self.pos += 1;
         // move to the next char in the iterator
-        self.peekable_chars.next()
+        let c = self.peekable_chars.next().map(|(_, c)| c);
+
+        // update the lexer's position to the byte offset of the new current char
+        self.pos = self
+            .peekable_chars
+            .peek()
+            .map(|(i, _)| *i)
+            .unwrap_or(self.input.len());
+
+        c
+    }
+
+    // move the lexer to the byte offset `pos` (which must be on a char boundary) in the input
+    // this only walks over the chars before `pos`, and does not lex them
+    fn seek(&mut self, pos: usize) {
+        self.peekable_chars = self.input.char_indices().peekable();
+
+        while self.peekable_chars.next_if(|(i, _)| *i < pos).is_some() {}
+
+        self.pos = pos;
     }
 
     // return the current char at the lexer's current position without advancing the pos
     fn current_char(&mut self) -> Option<char> {
-        self.peekable_chars.peek().cloned()
+        self.peekable_chars.peek().map(|(_, c)| *c)
+    }
+
+    // advance the lexer's pos past any digits of the given radix and `_` separators
+    fn skip_digits(&mut self, radix: u32) {
+        while let Some(c) = self.current_char() {
+            if c.is_digit(radix) || c == '_' {
+                self.advance();
+            } else {
+                break;
+            }
+        }
     }
 
     // advance the lexer's pos past any whitespace chars in the input stream
@@ -710,11 +1297,21 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    // check whether the input at `c` (the current char) is a `\n` or `\r\n` newline
+    fn at_newline(&self, c: char) -> bool {
+        c == '\n' || (c == '\r' && self.peek_next() == Some('\n'))
+    }
+
     // log and store information about an error encountered during the lexing process
     fn log_error(&self, error_kind: LexErrorKind) -> ErrorEmitted {
+        self.log_error_at(error_kind, self.pos)
+    }
+
+    // log an error at a given byte offset rather than the lexer's current position
+    fn log_error_at(&self, error_kind: LexErrorKind, pos: usize) -> ErrorEmitted {
         let err = LexError {
             error_kind,
-            position: Position::new(self.input.trim_start_matches('\n'), self.pos),
+            position: Position::new(self.src_id, pos),
         };
 
         self.handler.emit_err(CompilerError::Lex(err))
@@ -727,13 +1324,40 @@ impl<'a> Lexer<'a> {
         cloned_iter.next();
 
         // peek at the next char from the original iterator
-        cloned_iter.peek().cloned()
+        cloned_iter.peek().map(|(_, c)| *c)
+    }
+}
+
+/// Lex the input lazily, producing one token per call to `next()`.
+/// Errors are logged to the `Handler` as in `Lexer::lex()`.
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // abort once the `Handler` stops storing errors, as the rest would be dropped anyway
+        if self.handler.error_limit_reached() {
+            return None;
+        }
+
+        let token = self.scan_token();
+
+        self.track_delimiters(token.as_ref());
+
+        token
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use feo_ast::{item::Item, token::TokenTree};
+    use feo_error::explanation::{explain, EXPLANATIONS};
+    use feo_error::parser_error::ParserErrorKind;
+    use feo_error::suggestion::apply_suggestions;
+    use feo_types::span::{Span, Spanned};
+
+    use crate::{parse::ParseItem, parser::Parser};
+
     use super::*;
 
     // #[ignore]
@@ -896,14 +1520,22 @@ mod tests {
 
         let handler = Handler::default();
 
-        let mut lexer = Lexer::new(&source_code, handler);
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex", source_code);
 
-        if let Ok(ts) = lexer.lex() {
-            for t in ts.tokens().into_iter() {
+        let mut lexer = Lexer::new(&source_map, src_id, handler);
+
+        let ts = lexer.lex();
+
+        if lexer.errors().is_empty() {
+            for t in ts {
                 // for token in tokens {
                 match t {
                     Token::CharLit(c) => println!("CharLit: {:?}", c.into_inner()),
                     Token::StringLit(s) => println!("StringLit: {:?}", s.into_inner()),
+                    Token::ByteLit(b) => println!("ByteLit: {:?}", b.into_inner()),
+                    Token::ByteStringLit(bs) => println!("ByteStringLit: {:?}", bs.into_inner()),
                     Token::BoolLit(b) => println!("BoolLit: {:?}", b.into_inner()),
                     Token::IntLit(i) => println!("IntLit: {:?}", i.into_inner()),
                     Token::UIntLit(ui) => println!("UIntLit: {:?}", ui.into_inner()),
@@ -915,6 +1547,7 @@ mod tests {
                     Token::DocComment(dc) => println!("DocComment: {:?}", dc.content),
                     Token::Delim(d) => println!("Delim: {:?}", d.delim),
                     Token::Punc(p) => println!("Punc: {:?}", p.punc_kind),
+                    Token::Error(s, _) => println!("Error: {:?}", s.as_str(&source_map)),
                     // Token::TypeAnn(ta) => println!("BuiltInType: {:?}", ta.type_annotation),
                     Token::EOF => println!("end of file"),
                 };
@@ -923,9 +1556,994 @@ mod tests {
         } else {
             println!(
                 "error: {}, \nposition: line {}, col {}",
-                &lexer.errors().pop().expect("Error not found").error_kind(),
-                &lexer.errors().pop().expect("Error not found").line_col().0 + 731,
-                &lexer.errors().pop().expect("Error not found").line_col().1,
+                &lexer.errors().pop().expect("Error not found").message,
+                &lexer
+                    .errors()
+                    .pop()
+                    .expect("Error not found")
+                    .line_col(&source_map)
+                    .0,
+                &lexer
+                    .errors()
+                    .pop()
+                    .expect("Error not found")
+                    .line_col(&source_map)
+                    .1,
+            );
+        }
+    }
+
+    #[test]
+    fn lex_spans_share_source() {
+        let source_code = "let foo = bar;";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_spans_share_source", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let stream = lexer.lex();
+
+        let spans = stream
+            .tokens()
+            .iter()
+            .map(|t| t.span())
+            .collect::<Vec<Span>>();
+
+        assert!(spans.iter().all(|s| s.source_id() == src_id));
+
+        assert_eq!(
+            spans
+                .iter()
+                .map(|s| s.as_str(&source_map))
+                .collect::<Vec<&str>>(),
+            vec!["let", "foo", "=", "bar", ";"]
+        );
+    }
+
+    #[test]
+    fn lex_multibyte_chars() {
+        // multi-byte chars (2, 3 and 4 bytes in UTF-8) in every position that accepts them,
+        // followed by ASCII tokens whose spans must still line up with the source
+        let corpus = [
+            "let s = \"héllo wörld\"; let t = 1;",
+            "let s = \"日本語\" ; foo",
+            "let s = \"😀😀\"; bar(1, 2)",
+            "let c = 'é'; baz",
+            "let c = '日'; baz",
+            "let c = '😀'; baz",
+            "// ünïcödé line comment\nfoo.bar",
+            "/* block 😀 comment */ foo::bar",
+            "/// outer doc ☃\nstruct Foo {}",
+            "//! inner doc 日本\nimport foo;",
+            "\"ä\" \"ö\" \"ü\" x",
+        ];
+
+        for source_code in corpus {
+            let mut source_map = SourceMap::new();
+
+            let src_id = source_map.add_file("lex_multibyte_chars", source_code);
+
+            let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+            let stream = lexer.lex();
+
+            assert!(lexer.errors().is_empty(), "unable to lex `{}`", source_code);
+
+            for t in stream.tokens() {
+                let span = t.span();
+
+                // every span must be a valid UTF-8 byte range into the source
+                assert!(source_code.is_char_boundary(span.start()));
+                assert!(source_code.is_char_boundary(span.end()));
+
+                match &t {
+                    Token::Identifier(id) => assert_eq!(span.as_str(&source_map), id.name),
+                    Token::Keyword(k) => {
+                        assert_eq!(span.as_str(&source_map), k.keyword_kind.as_str())
+                    }
+                    Token::Punc(p) => assert_eq!(span.as_str(&source_map), p.punc_kind.as_str()),
+                    Token::CharLit(c) => {
+                        assert_eq!(
+                            span.as_str(&source_map),
+                            format!("'{}'", c.clone().into_inner().unwrap())
+                        )
+                    }
+                    Token::StringLit(s) => {
+                        assert!(span
+                            .as_str(&source_map)
+                            .contains(&s.clone().into_inner().unwrap()))
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lex_error_position_after_multibyte_chars() {
+        let source_code = "let s = \"😀\";\nlet c = ''";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_error_position", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        lexer.lex();
+
+        let error = lexer.errors().pop().expect("error not found");
+
+        assert_eq!(error.line_col(&source_map), (2, 10));
+    }
+
+    #[test]
+    fn lex_recovers_from_errors() {
+        let source_code = r#"
+        let a = '';
+        let b = "\q";
+        let c = 1 € 2;
+        let d = 'xy';
+        /* unclosed"#;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_recovers_from_errors", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let stream = lexer.lex();
+
+        let error_kinds = lexer
+            .errors()
+            .into_iter()
+            .map(|e| match e.error {
+                Some(CompilerError::Lex(l)) => l.error_kind,
+                _ => LexErrorKind::UnknownError,
+            })
+            .collect::<Vec<LexErrorKind>>();
+
+        assert_eq!(
+            error_kinds,
+            vec![
+                LexErrorKind::EmptyCharLiteral,
+                LexErrorKind::InvalidEscapeSequence,
+                LexErrorKind::InvalidChar('€'),
+                LexErrorKind::InvalidCharLiteral,
+                LexErrorKind::UnclosedBlockComment,
+            ]
+        );
+
+        let error_tokens = stream
+            .iter()
+            .filter_map(|t| match t {
+                Token::Error(s, _) => Some(s.as_str(&source_map)),
+                _ => None,
+            })
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            error_tokens,
+            vec!["''", "\"\\q\"", "€", "'xy'", "/* unclosed"]
+        );
+
+        // lexing carried on after each error
+        let identifiers = stream
+            .iter()
+            .filter_map(|t| match t {
+                Token::Identifier(id) => Some(id.name.clone()),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(identifiers, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn lex_mismatched_delimiters() {
+        let source_code = "foo(a[0)];\n}\nbar({";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_mismatched_delimiters", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let stream = lexer.lex();
+
+        let errors = lexer
+            .errors()
+            .into_iter()
+            .map(|e| match e.error {
+                Some(CompilerError::Lex(l)) => (l.error_kind, l.position.line_col(&source_map)),
+                _ => (LexErrorKind::UnknownError, (0, 0)),
+            })
+            .collect::<Vec<(LexErrorKind, (usize, usize))>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    LexErrorKind::MismatchedDelimiters {
+                        open: '[',
+                        open_position: Position::new(src_id, 5),
+                        close: ')',
+                    },
+                    (1, 8)
+                ),
+                (LexErrorKind::UnexpectedCloseDelimiter, (1, 9)),
+                (LexErrorKind::UnexpectedCloseDelimiter, (2, 1)),
+                (LexErrorKind::UnclosedDelimiters, (3, 4)),
+                (LexErrorKind::UnclosedDelimiters, (3, 5)),
+            ]
+        );
+
+        // the open delimiter is pointed to by a secondary label
+        let diagnostic = lexer.errors().remove(0);
+
+        assert_eq!(diagnostic.code.as_deref(), Some("E0114"));
+        assert!(explain("E0114").is_some());
+        assert_eq!(diagnostic.span().as_str(&source_map), ")");
+        assert_eq!(diagnostic.secondary.len(), 1);
+        assert_eq!(diagnostic.secondary[0].span.as_str(&source_map), "[");
+        assert_eq!(diagnostic.secondary[0].message, "`[` opened here");
+
+        let trees = stream.token_trees();
+
+        // `foo`, `( .. )`, `]`, `;`, `}`, `bar`, `( .. )`
+        assert_eq!(trees.len(), 7);
+
+        match &trees[1] {
+            TokenTree::Group(g) => {
+                assert_eq!(g.span().as_str(&source_map), "(a[0)");
+                assert!(g.close.is_some());
+
+                // `[0` is left unclosed inside the parentheses
+                match &g.trees[1] {
+                    TokenTree::Group(inner) => assert!(inner.close.is_none()),
+                    _ => panic!("expected a group"),
+                }
+            }
+            _ => panic!("expected a group"),
+        }
+
+        match &trees[6] {
+            TokenTree::Group(g) => {
+                assert!(g.close.is_none());
+                assert_eq!(g.trees.len(), 1);
+            }
+            _ => panic!("expected a group"),
+        }
+    }
+
+    #[test]
+    fn lex_numeric_literals() {
+        let source_code = "0b1010 0o17 0xffu64 10u8 7i32 -7 1.0f32 2.5E-3 1e18 \
+            1_000_000e18 5u256 0xff 0x1_0000_0000_0000_0000 300u8 -1u64 1.5u8 7u7";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_numeric_literals", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer.by_ref().collect::<Vec<Token>>();
+
+        let literals = tokens
+            .iter()
+            .map(|t| match t {
+                Token::UIntLit(ui) => format!("{:?}", ui.clone().into_inner().unwrap()),
+                Token::IntLit(i) => format!("{:?}", i.clone().into_inner().unwrap()),
+                Token::FloatLit(f) => format!("{:?}", f.clone().into_inner().unwrap()),
+                Token::U256Lit(u) => format!("U256({})", u.clone().into_inner().unwrap()),
+                Token::Error(s, _) => format!("Error({})", s.as_str(&source_map)),
+                t => panic!("unexpected token: {}", t),
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            literals,
+            vec![
+                "U64(10)",
+                "U64(15)",
+                "U64(255)",
+                "U8(10)",
+                "I32(7)",
+                "I64(-7)",
+                "F32(1.0)",
+                "F64(0.0025)",
+                "U64(1000000000000000000)",
+                "U256(1000000000000000000000000)",
+                "U256(5)",
+                // unsuffixed literals in any radix are `u64`s, unless they are too large
+                "U64(255)",
+                "U256(18446744073709551616)",
+                "Error(300u8)",
+                "Error(-1u64)",
+                "Error(1.5u8)",
+                "Error(7u7)",
+            ]
+        );
+
+        let error_kinds = lexer
+            .errors()
+            .into_iter()
+            .map(|e| match e.error {
+                Some(CompilerError::Parser(p)) => p.error_kind.to_string(),
+                Some(CompilerError::Lex(l)) => l.error_kind.to_string(),
+                _ => String::new(),
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            error_kinds,
+            vec![
+                ParserErrorKind::ParseUIntError.to_string(),
+                ParserErrorKind::ParseUIntError.to_string(),
+                ParserErrorKind::ParseUIntError.to_string(),
+                LexErrorKind::InvalidNumericSuffix.to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn lex_raw_and_byte_literals() {
+        let source_code = r####"
+        let a = r"C:\path";
+        let b = r#"a "quoted" word"#;
+        let c = "\u{1F600} \x7F";
+        let d = b"ab\xFF\n";
+        let e = b'a';
+        let f = b'\x00';
+        let g = br#"raw "bytes""#;
+        let h = b'é';
+        let i = "\x80";
+        let j = b"\u{41}";
+        let bar = r#identifier;
+        "####;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_raw_and_byte_literals", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer.by_ref().collect::<Vec<Token>>();
+
+        let literals = tokens
+            .iter()
+            .filter_map(|t| match t {
+                Token::StringLit(s) => Some(format!("{:?}", s.clone().into_inner().unwrap())),
+                Token::ByteStringLit(bs) => {
+                    Some(format!("b{:?}", bs.clone().into_inner().unwrap()))
+                }
+                Token::ByteLit(b) => Some(format!("b{:?}", b.clone().into_inner().unwrap())),
+                Token::Error(s, _) => Some(format!("Error({})", s.as_str(&source_map))),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            literals,
+            vec![
+                r#""C:\\path""#,
+                r#""a \"quoted\" word""#,
+                r#""😀 \u{7f}""#,
+                "b[97, 98, 255, 10]",
+                "b97",
+                "b0",
+                "b[114, 97, 119, 32, 34, 98, 121, 116, 101, 115, 34]",
+                "Error(b'é')",
+                r#"Error("\x80")"#,
+                r#"Error(b"\u{41}")"#,
+            ]
+        );
+
+        let error_kinds = lexer
+            .errors()
+            .into_iter()
+            .map(|e| match e.error {
+                Some(CompilerError::Lex(l)) => l.error_kind,
+                _ => LexErrorKind::UnknownError,
+            })
+            .collect::<Vec<LexErrorKind>>();
+
+        assert_eq!(
+            error_kinds,
+            vec![
+                LexErrorKind::NonAsciiByteLiteral,
+                LexErrorKind::InvalidEscapeSequence,
+                LexErrorKind::InvalidEscapeSequence,
+            ]
+        );
+    }
+
+    #[test]
+    fn lex_block_comments() {
+        let source_code = r#"
+        /* a * b */
+        /* outer /* inner */ still outer */
+        /**/
+        /**
+         * Outer block doc comment
+         * spanning two lines
+         */
+        /*! inner block doc comment */
+        x
+        /* unclosed /* nested */"#;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_block_comments", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer.by_ref().collect::<Vec<Token>>();
+
+        let comments = tokens
+            .iter()
+            .map(|t| match t {
+                Token::Comment(c) => format!("Comment({})", c.data),
+                Token::DocComment(dc) => format!("{:?}({})", dc.doc_comment_kind, dc.content),
+                t => t.to_string(),
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            comments,
+            vec![
+                "Comment(/* a * b */)",
+                "Comment(/* outer /* inner */ still outer */)",
+                "Comment(/**/)",
+                "OuterBlockDocComment(Outer block doc comment\nspanning two lines)",
+                "InnerBlockDocComment(inner block doc comment)",
+                "`x`",
+                "invalid token `/* unclosed /* nested */`",
+            ]
+        );
+
+        let error = lexer.errors().pop().expect("error not found");
+
+        match error.error {
+            Some(CompilerError::Lex(l)) => {
+                assert_eq!(l.error_kind, LexErrorKind::UnclosedBlockComment)
+            }
+            _ => panic!("expected a lex error"),
+        }
+    }
+
+    #[test]
+    fn lex_unicode_and_raw_identifiers() {
+        // `cafe\u{301}` is `café` with a combining acute accent (i.e., not in NFC)
+        let source_code = "let café = cafe\u{301}; let 数量 = r#storage.r#topic; r#self";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_unicode_identifiers", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer.by_ref().collect::<Vec<Token>>();
+
+        let identifiers = tokens
+            .iter()
+            .filter_map(|t| match t {
+                Token::Identifier(id) => {
+                    Some((id.name.clone(), id.is_raw, id.span().as_str(&source_map)))
+                }
+                _ => None,
+            })
+            .collect::<Vec<(String, bool, &str)>>();
+
+        assert_eq!(
+            identifiers,
+            vec![
+                ("café".to_string(), false, "café"),
+                ("café".to_string(), false, "cafe\u{301}"),
+                ("数量".to_string(), false, "数量"),
+                ("storage".to_string(), true, "r#storage"),
+                ("topic".to_string(), true, "r#topic"),
+            ]
+        );
+
+        let error = lexer.errors().pop().expect("error not found");
+
+        match error.error {
+            Some(CompilerError::Lex(l)) => {
+                assert_eq!(l.error_kind, LexErrorKind::InvalidRawIdentifier)
+            }
+            _ => panic!("expected a lex error"),
+        }
+    }
+
+    #[test]
+    fn lex_punctuation_longest_match() {
+        let source_code = "a>-b; !-x; &*p; x=-1; y<<=2; z>>=w; a^=b; a|=b; a&=b; a..=b; c::*";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_punctuation", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer.by_ref().collect::<Vec<Token>>();
+
+        assert!(lexer.errors().is_empty());
+
+        let punctuation = tokens
+            .iter()
+            .filter_map(|t| match t {
+                Token::Punc(p) if p.punc_kind != PuncKind::Semicolon => Some(p.punc_kind.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            punctuation,
+            vec![">", "-", "!", "-", "&", "*", "=", "<<=", ">>=", "^=", "|=", "&=", "..=", "::*"]
+        );
+    }
+
+    #[test]
+    fn lex_tuple_index_chain() {
+        let source_code = "t.0.1; self.0.2; x.1.0.method(); 0.5; 1..2";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_tuple_index_chain", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let tokens = lexer
+            .lex()
+            .tokens()
+            .iter()
+            .map(|t| t.span().as_str(&source_map))
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            tokens,
+            vec![
+                "t", ".", "0", ".", "1", ";", "self", ".", "0", ".", "2", ";", "x", ".", "1", ".",
+                "0", ".", "method", "(", ")", ";", "0.5", ";", "1", "..", "2"
+            ]
+        );
+    }
+
+    #[test]
+    fn lex_lazily() {
+        let source_code = "let x = foo(1, 2.5); { y";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_lazily", source_code);
+
+        let stream = Lexer::new(&source_map, src_id, Handler::default()).lex();
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let first = lexer.next().expect("token not found");
+        assert_eq!(first.span().as_str(&source_map), "let");
+
+        let rest = lexer.by_ref().take(9).collect::<Vec<Token>>();
+        assert_eq!(rest.last().map(|t| t.span().as_str(&source_map)), Some(";"));
+
+        // the unclosed `{` is only reported once the input is exhausted
+        assert!(lexer.errors().is_empty());
+
+        let tail = lexer.by_ref().collect::<Vec<Token>>();
+        assert!(lexer.next().is_none());
+        assert_eq!(lexer.errors().len(), 1);
+
+        let lazy_spans = std::iter::once(first)
+            .chain(rest)
+            .chain(tail)
+            .map(|t| t.span())
+            .collect::<Vec<Span>>();
+
+        let spans = stream.iter().map(|t| t.span()).collect::<Vec<Span>>();
+
+        assert_eq!(lazy_spans, spans);
+    }
+
+    #[test]
+    fn lex_lossless() {
+        let source_code = "// comment\r\nlet c = '\\n';\t \n\n  /* block\n comment */ foo(b\"x\", r#\"y\"#) \r\n$ ";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_lossless", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let stream = lexer.lex_lossless();
+
+        assert_eq!(stream.source_text(&source_map), source_code);
+
+        let tokens = stream.tokens();
+
+        // trailing trivia runs up to and including the first newline after a token
+        assert_eq!(tokens[0].text.as_str(&source_map), "// comment");
+        assert_eq!(tokens[0].trailing_trivia.len(), 1);
+        assert_eq!(
+            tokens[0].trailing_trivia[0].span.as_str(&source_map),
+            "\r\n"
+        );
+
+        // the text of a char literal includes its quotes
+        assert_eq!(tokens[4].text.as_str(&source_map), "'\\n'");
+
+        let semicolon = &tokens[5];
+
+        assert_eq!(
+            semicolon
+                .trailing_trivia
+                .iter()
+                .map(|t| t.kind)
+                .collect::<Vec<TriviaKind>>(),
+            vec![TriviaKind::Whitespace, TriviaKind::Newline]
+        );
+
+        // the blank line and the indentation lead the next token
+        let block_comment = &tokens[6];
+
+        assert_eq!(
+            block_comment.text.as_str(&source_map),
+            "/* block\n comment */"
+        );
+        assert_eq!(
+            block_comment
+                .leading_trivia
+                .iter()
+                .map(|t| t.span.as_str(&source_map))
+                .collect::<Vec<&str>>(),
+            vec!["\n", "  "]
+        );
+
+        // the invalid char is kept as an error token, and whitespace at the end of the file
+        // that is not trailing trivia is kept separately
+        let last = tokens.last().expect("token not found");
+
+        assert!(matches!(last.token, Token::Error(..)));
+        assert_eq!(last.trailing_trivia[0].span.as_str(&source_map), " ");
+        assert!(stream.eof_trivia().is_empty());
+
+        assert_eq!(
+            stream.into_token_stream().len(),
+            Lexer::new(&source_map, src_id, Handler::default())
+                .lex()
+                .len()
+        );
+    }
+
+    #[test]
+    fn lex_contextual_keywords() {
+        let source_code = "let storage = test.topic; r#abi; abi";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lex_contextual_keywords", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+        let stream = lexer.lex();
+
+        let keywords = stream
+            .iter()
+            .filter_map(|t| match t {
+                Token::Keyword(k) => Some(k.keyword_kind.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<&str>>();
+
+        let identifiers = stream
+            .iter()
+            .filter_map(|t| match t {
+                Token::Identifier(id) => Some(id.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<&str>>();
+
+        assert_eq!(keywords, vec!["let"]);
+        assert_eq!(identifiers, vec!["storage", "test", "topic", "abi", "abi"]);
+    }
+
+    #[test]
+    fn relex_edits() {
+        let source_code = "let a = foo(1, 2.5);\nlet t = x.0; /* c */ \"s\"\nr##x; 1.x { b }";
+
+        // (start, end, new text) of each edit, made to the original source
+        let edits = [
+            (4, 5, "abc"),  // rename an identifier
+            (5, 5, "bc"),   // extend an identifier
+            (8, 8, "/* "),  // open a block comment that swallows the rest of the file
+            (33, 33, "\""), // open a string that swallows the block comment
+            (31, 32, "1"),  // change the tuple index
+            (49, 50, "\""), // turn `r##x` into an (unclosed) raw string
+            (54, 55, "5"),  // turn `1.x` into a float
+            (20, 21, ""),   // delete the newline between statements
+            (12, 19, ""),   // delete the arguments and the close delimiter
+            (0, 0, "  "),   // insert at the start
+            (61, 61, " }"), // insert at the end
+        ];
+
+        for (start, end, new_text) in edits {
+            let mut source_map = SourceMap::new();
+
+            let src_id = source_map.add_file("relex_edits", source_code);
+
+            let previous = Lexer::new(&source_map, src_id, Handler::default()).lex();
+
+            let edit = TextEdit::new(start, end, new_text);
+
+            let relexed_id = source_map.edit_file(src_id, &edit);
+
+            // the result is the same as lexing the edited file from scratch
+            let edited_code = edit.apply(source_code);
+            let edited_id = source_map.add_file("relex_edits", &edited_code);
+
+            let mut relexer = Lexer::new(&source_map, relexed_id, Handler::default());
+
+            let relexed = relexer.relex(&previous, edit.clone());
+
+            let mut lexer = Lexer::new(&source_map, edited_id, Handler::default());
+
+            let expected = lexer.lex();
+
+            let token_data = |stream: &TokenStream| {
+                stream
+                    .iter()
+                    .map(|t| {
+                        (
+                            t.span().start(),
+                            t.span().as_str(&source_map),
+                            mem::discriminant(t),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(
+                token_data(&relexed),
+                token_data(&expected),
+                "edit {:?} to `{}`",
+                edit,
+                source_code
+            );
+
+            // delimiters are matched across the whole file
+            let delimiter_errors = |errors: Vec<Diagnostic>| {
+                errors
+                    .into_iter()
+                    .filter(|e| match &e.error {
+                        Some(CompilerError::Lex(e)) => matches!(
+                            e.error_kind,
+                            LexErrorKind::UnclosedDelimiters
+                                | LexErrorKind::UnexpectedCloseDelimiter
+                                | LexErrorKind::MismatchedDelimiters { .. }
+                        ),
+                        _ => false,
+                    })
+                    .count()
+            };
+
+            assert_eq!(
+                delimiter_errors(relexer.errors()),
+                delimiter_errors(lexer.errors()),
+                "edit {:?}",
+                edit
+            );
+        }
+    }
+
+    #[test]
+    fn to_source_round_trip() {
+        let source_code = r###"
+        //! Inner doc comment
+        ///
+        /// Outer doc comment
+        #[abi] #![storage]
+        /**
+         * Block doc comment
+         *
+         * with `*` in it
+         */
+        pub struct Foo<T> { x: u256, y: (u8, T) } // line comment
+        impl Foo { func bar(&mut self, r#type: i64) -> Option<u8> {
+            let s = "tab\t \"quoted\" \\ \u{1F600} é\n" ; let r = r#"raw "str""#;
+            let c = ['\'', '"', '\\', '\0', 'é', '\u{301}'];
+            let bs = b"bytes\x00\xff\"" ; let b = b'\'';
+            let n = 1 + -2 - 3 -4 * 0x1f / 0b101u8 % 1_000e18 + 7i32 + 255u8;
+            let f = 1.5 + 2.5E-3 - 1e18f32 * -0.1f64;
+            t.0.1.method()?.2 /* block comment */ ; x..=y; a..b; foo::bar::*;
+            if !(a != b && c <= d) { return } else { r#storage.x[0](1) }
+        } }
+        "###;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("to_source", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+        let stream = lexer.lex();
+
+        assert!(lexer.errors().is_empty(), "{:?}", lexer.errors());
+
+        let output = stream.to_source();
+
+        let output_id = source_map.add_file("to_source_output", &output);
+
+        let mut output_lexer = Lexer::new(&source_map, output_id, Handler::default());
+        let output_stream = output_lexer.lex();
+
+        assert!(output_lexer.errors().is_empty(), "{output}");
+
+        let tokens = |ts: &TokenStream| {
+            ts.iter()
+                .map(|t| (std::mem::discriminant(t), t.to_source()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(tokens(&stream), tokens(&output_stream), "{output}");
+
+        // printing the re-lexed tokens gives the same output
+        assert_eq!(output_stream.to_source(), output);
+
+        let src_id = source_map.add_file("to_source_spacing", "foo (x, -1) .bar[ 0 ];");
+
+        assert_eq!(
+            Lexer::new(&source_map, src_id, Handler::default())
+                .lex()
+                .to_source(),
+            "foo(x, -1).bar[0];"
+        );
+    }
+
+    #[test]
+    fn fix_unclosed_delimiters() {
+        let source_code = "func foo() {\n    bar(baz[0\n";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("fix.feo", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+        lexer.lex();
+
+        let errors = lexer.errors();
+
+        // the first error inserts all the close delimiters, innermost first
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].suggestions.len(), 1);
+        assert!(errors[1..].iter().all(|e| e.suggestions.is_empty()));
+
+        let fixed = apply_suggestions(source_code, &errors);
+
+        assert_eq!(fixed, "func foo() {\n    bar(baz[0\n])}");
+
+        let fixed_id = source_map.add_file("fixed.feo", &fixed);
+
+        let mut fixed_lexer = Lexer::new(&source_map, fixed_id, Handler::default());
+        fixed_lexer.lex();
+
+        assert!(fixed_lexer.errors().is_empty());
+    }
+
+    #[test]
+    fn lint_levels_and_suppression() {
+        let source_code = "#[deny(unnecessary_raw_identifier, no_such_lint)]\n\
+                           const r#foo: u64 = 1;\n\
+                           const r#bar: u64 = 2;";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("lints.feo", source_code);
+
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+        let stream = lexer.lex();
+
+        // lint levels are only known after parsing, so warnings are not errors yet
+        assert!(lexer.errors().is_empty());
+
+        let mut parser = Parser::new(stream, lexer.handler().clone());
+
+        for _ in 0..2 {
+            Item::parse(&mut parser)
+                .expect("unable to parse item")
+                .expect("item not found");
+            parser.next_token();
+        }
+
+        let (errors, warnings) = parser.handler().clone().get_inner();
+
+        // `r#foo` is in the item with the `deny` attribute, `r#bar` is not
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].lint.map(|l| l.name),
+            Some("unnecessary_raw_identifier")
+        );
+        assert_eq!(errors[0].line_col(&source_map), (2, 7));
+
+        let messages = warnings
+            .iter()
+            .map(|w| (w.message.as_str(), w.line_col(&source_map)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                ("unknown lint: `no_such_lint`", (1, 36)),
+                ("unnecessary raw identifier: `bar` is not a keyword", (3, 7)),
+            ]
+        );
+        assert_eq!(
+            warnings[1].notes,
+            ["`#[warn(unnecessary_raw_identifier)]` on by default"]
+        );
+        assert_eq!(
+            apply_suggestions(source_code, &warnings),
+            source_code.replace("r#bar", "bar")
+        );
+    }
+
+    #[test]
+    fn lex_to_error_limit() {
+        let source_code = "let a = 1 € 2;\nlet b = 'ab';\nlet c = 3 $ 4;\nlet d = '';";
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("policies.feo", source_code);
+
+        // the lexer stops at the error limit
+        let handler = Handler::default();
+        handler.set_error_limit(Some(2));
+
+        let mut lexer = Lexer::new(&source_map, src_id, handler);
+        let stream = lexer.lex();
+
+        assert!(lexer.handler().error_limit_reached());
+        assert_eq!(lexer.errors().len(), 2);
+
+        // the lexer does not look for more errors after the second one (in `'ab'`)
+        assert_eq!(lexer.handler().num_dropped_errors(), 0);
+        assert_eq!(stream.iter().count(), 11);
+
+        // without a limit, every error is reported and the whole input is lexed
+        let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+        let stream = lexer.lex();
+
+        assert_eq!(lexer.errors().len(), 4);
+        assert_eq!(stream.iter().count(), 24);
+    }
+
+    #[test]
+    fn lex_fixed_explanation_examples() {
+        for (code, explanation) in EXPLANATIONS {
+            // the first example causes the error, and the last one (if any) shows how to fix it
+            let examples = explanation
+                .split("```feo\n")
+                .skip(1)
+                .filter_map(|e| e.split("```").next())
+                .collect::<Vec<&str>>();
+
+            let Some(example) = examples.last().filter(|_| examples.len() > 1) else {
+                continue;
+            };
+
+            let mut source_map = SourceMap::new();
+
+            let src_id = source_map.add_file(code, example);
+
+            let mut lexer = Lexer::new(&source_map, src_id, Handler::default());
+
+            lexer.lex();
+
+            assert!(
+                lexer.errors().is_empty(),
+                "fixed example of {} does not lex: {:#?}",
+                code,
+                lexer.errors()
             );
         }
     }
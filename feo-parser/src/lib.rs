@@ -1,14 +1,179 @@
 mod attribute;
 mod expression;
 mod item;
-mod lexer;
+pub mod lexer;
 mod parse;
 mod parser;
 mod path;
 mod pattern;
 mod peek;
 pub mod precedence;
+mod source_unit;
 mod statement;
 pub mod test_utils;
+mod token_buffer;
 mod ty;
 pub mod utils;
+
+use feo_ast::{
+    expression::Expression, item::Item, pattern::Pattern, source_unit::SourceUnit, token::Token,
+    Type,
+};
+use feo_error::{
+    diagnostic::Diagnostic,
+    error::CompilerError,
+    handler::{Handler, SharedHandler},
+    parser_error::{ParserError, ParserErrorKind},
+};
+use feo_types::{
+    source_map::{SourceId, SourceMap},
+    span::{Position, Span},
+};
+
+use crate::{
+    lexer::Lexer,
+    parse::{ParseExpr, ParseItem, ParsePatt, ParseType},
+    parser::Parser,
+};
+
+/// Lex and parse the source code of a file, which is added to `source_map` under `file_id`
+/// (e.g., its path), and return its syntax tree.
+/// Errors and warnings are logged to `handler`; the returned tree holds the items that were
+/// parsed successfully, so check `handler.has_errors()` before using it.
+pub fn parse_file(
+    source_map: &mut SourceMap,
+    source: &str,
+    file_id: &str,
+    handler: &Handler,
+) -> SourceUnit {
+    let src_id = source_map.add_file(file_id, source);
+
+    with_parser(source_map, src_id, handler, |parser| {
+        source_unit::parse_source_unit(parser, Span::new(src_id, 0, source.len()))
+    })
+}
+
+/// Parse a fragment of source code as an expression (e.g., `x + 2`), which is added to
+/// `source_map` as a file of its own.
+/// Return `None` if the fragment is not exactly one expression (the error is logged to `handler`).
+pub fn parse_expr(
+    source_map: &mut SourceMap,
+    source: &str,
+    handler: &Handler,
+) -> Option<Expression> {
+    parse_fragment(source_map, source, handler, "expression", Expression::parse)
+}
+
+/// Parse a fragment of source code as a type (e.g., `[u64; 4]`), which is added to
+/// `source_map` as a file of its own.
+/// Return `None` if the fragment is not exactly one type (the error is logged to `handler`).
+pub fn parse_type(source_map: &mut SourceMap, source: &str, handler: &Handler) -> Option<Type> {
+    parse_fragment(source_map, source, handler, "type", Type::parse)
+}
+
+/// Parse a fragment of source code as a pattern (e.g., `(a, _)`), which is added to
+/// `source_map` as a file of its own.
+/// Return `None` if the fragment is not exactly one pattern (the error is logged to `handler`).
+pub fn parse_pattern(
+    source_map: &mut SourceMap,
+    source: &str,
+    handler: &Handler,
+) -> Option<Pattern> {
+    parse_fragment(source_map, source, handler, "pattern", Pattern::parse)
+}
+
+/// Parse a fragment of source code as an item (e.g., `const X: u64 = 2;`), which is added to
+/// `source_map` as a file of its own.
+/// Return `None` if the fragment is not exactly one item (the error is logged to `handler`).
+pub fn parse_item(source_map: &mut SourceMap, source: &str, handler: &Handler) -> Option<Item> {
+    parse_fragment(source_map, source, handler, "item", Item::parse)
+}
+
+// run `f` on a parser that pulls tokens from a lexer over the file as it goes, with both
+// logging to a fork of `handler` that is merged back afterwards
+fn with_parser<T>(
+    source_map: &SourceMap,
+    src_id: SourceId,
+    handler: &Handler,
+    f: impl FnOnce(&mut Parser) -> T,
+) -> T {
+    let shared = SharedHandler::from(handler.fork());
+
+    let result = {
+        let lexer = Lexer::new(source_map, src_id, shared.clone());
+        let mut parser = Parser::new(lexer, shared.clone());
+        f(&mut parser)
+    };
+
+    handler.merge(shared.into_inner());
+
+    result
+}
+
+fn parse_fragment<T>(
+    source_map: &mut SourceMap,
+    source: &str,
+    handler: &Handler,
+    expected: &str,
+    parse: fn(&mut Parser) -> Result<Option<T>, Vec<Diagnostic>>,
+) -> Option<T> {
+    let src_id = source_map.add_file("<fragment>", source);
+
+    with_parser(source_map, src_id, handler, |parser| {
+        let num_errors = parser.errors().len();
+
+        let node = match parse(parser) {
+            Ok(Some(n)) => n,
+            Ok(None) => {
+                log_expected(parser, src_id, expected);
+
+                return None;
+            }
+            Err(diagnostics) => {
+                // the diagnostics are usually in the `Handler` already (and are not added twice)
+                for d in diagnostics {
+                    parser.handler().emit_diagnostic(d);
+                }
+
+                if parser.errors().len() == num_errors {
+                    log_expected(parser, src_id, expected);
+                }
+
+                return None;
+            }
+        };
+
+        parser.next_token();
+
+        if let Some(t) = parser.current_token() {
+            parser.log_error(ParserErrorKind::UnexpectedToken {
+                expected: Token::EOF.to_string(),
+                found: t.to_string(),
+            });
+
+            return None;
+        }
+
+        Some(node)
+    })
+}
+
+// log that `expected` was not found at the current token (or at the start of the fragment,
+// if it is empty, where the parser has no token to point to)
+fn log_expected(parser: &Parser, src_id: SourceId, expected: &str) {
+    let error_kind = ParserErrorKind::UnexpectedToken {
+        expected: expected.to_string(),
+        found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+    };
+
+    if parser.pos() == 0 && parser.current_token().is_none() {
+        parser
+            .handler()
+            .emit_err(CompilerError::Parser(ParserError {
+                error_kind,
+                position: Position::new(src_id, 0),
+            }));
+    } else {
+        parser.log_error(error_kind);
+    }
+}
@@ -27,54 +27,56 @@ use feo_ast::{
     },
     Type,
 };
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
     literal::LiteralKind,
     punctuation::PuncKind,
+    span::Spanned,
     BuiltInType, Delimiter, Identifier, Keyword, Punctuation,
 };
 
 use crate::{
     parser::Parser,
     test_utils::{self, LogMsgType},
+    utils,
 };
 
 pub trait ParseTerm {
     /// Parses literals, attributes, paths, parenthesized expressions and
     /// helper types (e.g., `StructExprField`).
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
 
 pub trait ParseExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
 
 pub trait ParseItem {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
 
 pub trait ParsePatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
 
 pub trait ParseStatement {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
 
 pub trait ParseType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized;
 }
@@ -82,7 +84,7 @@ pub trait ParseType {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseExpr for Expression {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -126,6 +128,8 @@ impl ParseExpr for Expression {
                             return Ok(Some(Expression::MethodCallExpr(mc)));
                         } else if let Some(fa) = FieldAccessExpr::parse(parser).unwrap_or(None) {
                             return Ok(Some(Expression::FieldAccessExpr(fa)));
+                        } else if let Some(ti) = TupleIndexExpr::parse(parser).unwrap_or(None) {
+                            return Ok(Some(Expression::TupleIndexExpr(ti)));
                         }
 
                         if let Some(pth) = PathInExpr::parse(parser).unwrap_or(None) {
@@ -232,6 +236,8 @@ impl ParseExpr for Expression {
                             return Ok(Some(Expression::MethodCallExpr(mc)));
                         } else if let Some(fa) = FieldAccessExpr::parse(parser).unwrap_or(None) {
                             return Ok(Some(Expression::FieldAccessExpr(fa)));
+                        } else if let Some(ti) = TupleIndexExpr::parse(parser).unwrap_or(None) {
+                            return Ok(Some(Expression::TupleIndexExpr(ti)));
                         }
                     }
 
@@ -291,7 +297,12 @@ impl ParseExpr for Expression {
                     | PuncKind::MinusEquals
                     | PuncKind::AsteriskEquals
                     | PuncKind::ForwardSlashEquals
-                    | PuncKind::PercentEquals => {
+                    | PuncKind::PercentEquals
+                    | PuncKind::AmpersandEquals
+                    | PuncKind::PipeEquals
+                    | PuncKind::CaretEquals
+                    | PuncKind::DblLessThanEquals
+                    | PuncKind::DblGreaterThanEquals => {
                         if let Some(cae) = CompoundAssignmentExpr::parse(parser).unwrap_or(None) {
                             return Ok(Some(Expression::OperatorExpr(
                                 OperatorExprKind::CompoundAssign(cae),
@@ -598,6 +609,26 @@ impl ParseExpr for Expression {
                 | Some(Punctuation {
                     punc_kind: PuncKind::PercentEquals,
                     ..
+                })
+                | Some(Punctuation {
+                    punc_kind: PuncKind::AmpersandEquals,
+                    ..
+                })
+                | Some(Punctuation {
+                    punc_kind: PuncKind::PipeEquals,
+                    ..
+                })
+                | Some(Punctuation {
+                    punc_kind: PuncKind::CaretEquals,
+                    ..
+                })
+                | Some(Punctuation {
+                    punc_kind: PuncKind::DblLessThanEquals,
+                    ..
+                })
+                | Some(Punctuation {
+                    punc_kind: PuncKind::DblGreaterThanEquals,
+                    ..
                 }) => {
                     if let Some(cae) = CompoundAssignmentExpr::parse(parser).unwrap_or(None) {
                         return Ok(Some(Expression::OperatorExpr(
@@ -621,7 +652,7 @@ impl ParseExpr for Expression {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseExpr for ExprWithoutBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -724,7 +755,12 @@ impl ParseExpr for ExprWithoutBlock {
                     | PuncKind::MinusEquals
                     | PuncKind::AsteriskEquals
                     | PuncKind::ForwardSlashEquals
-                    | PuncKind::PercentEquals => {
+                    | PuncKind::PercentEquals
+                    | PuncKind::AmpersandEquals
+                    | PuncKind::PipeEquals
+                    | PuncKind::CaretEquals
+                    | PuncKind::DblLessThanEquals
+                    | PuncKind::DblGreaterThanEquals => {
                         if let Some(cae) = CompoundAssignmentExpr::parse(parser).unwrap_or(None) {
                             return Ok(Some(ExprWithoutBlock::OperatorExpr(
                                 OperatorExprKind::CompoundAssign(cae),
@@ -1032,6 +1068,26 @@ impl ParseExpr for ExprWithoutBlock {
                     | Some(Punctuation {
                         punc_kind: PuncKind::PercentEquals,
                         ..
+                    })
+                    | Some(Punctuation {
+                        punc_kind: PuncKind::AmpersandEquals,
+                        ..
+                    })
+                    | Some(Punctuation {
+                        punc_kind: PuncKind::PipeEquals,
+                        ..
+                    })
+                    | Some(Punctuation {
+                        punc_kind: PuncKind::CaretEquals,
+                        ..
+                    })
+                    | Some(Punctuation {
+                        punc_kind: PuncKind::DblLessThanEquals,
+                        ..
+                    })
+                    | Some(Punctuation {
+                        punc_kind: PuncKind::DblGreaterThanEquals,
+                        ..
                     }) => {
                         if let Some(cae) = CompoundAssignmentExpr::parse(parser).unwrap_or(None) {
                             return Ok(Some(ExprWithoutBlock::OperatorExpr(
@@ -1191,7 +1247,7 @@ impl ParseExpr for ExprWithoutBlock {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseExpr for ExprWithBlock {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -1264,7 +1320,7 @@ impl ParseExpr for ExprWithBlock {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseItem for Item {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -1275,15 +1331,36 @@ impl ParseItem for Item {
             ..
         }) = parser.peek_current()
         {
-            if let Some(_) = OuterAttr::parse(parser).unwrap_or(None) {
-                parser.next_token();
+            let attributes_opt = utils::get_attributes::<OuterAttr>(parser)?;
 
-                if let Some(i) = get_item_by_keyword(parser).unwrap_or(None) {
-                    return Ok(Some(i));
+            if let Some(mut i) = get_item_by_keyword(parser)? {
+                // the attributes were parsed before the kind of item was known,
+                // so the item's own parser did not see them
+                i.set_outer_attributes(attributes_opt);
+
+                parser.set_lint_levels(i.outer_attributes().iter().map(|a| &a.attribute), i.span());
+
+                if let Some(name) = i.name() {
+                    parser.declare_identifier(name);
                 }
+
+                return Ok(Some(i));
             }
         } else if let Some(_) = parser.peek_current::<Keyword>() {
-            return get_item_by_keyword(parser);
+            let item_opt = get_item_by_keyword(parser)?;
+
+            if let Some(i) = &item_opt {
+                parser.set_lint_levels(i.outer_attributes().iter().map(|a| &a.attribute), i.span());
+
+                if let Some(name) = i.name() {
+                    parser.declare_identifier(name);
+                }
+            }
+
+            return Ok(item_opt);
+        } else if parser.check_unknown_word() {
+            // e.g., `fn foo() {}` or `strcut Foo {}`
+            return Err(parser.errors());
         } else {
             return Ok(None);
         }
@@ -1295,7 +1372,7 @@ impl ParseItem for Item {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParsePatt for Pattern {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -1439,13 +1516,17 @@ impl ParsePatt for Pattern {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseStatement for Statement {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
         //test_utils::log_msg(LogMsgType::Detect, "Statement", parser);
 
         if let Some(_) = parser.peek_current::<Identifier>() {
+            if parser.check_unknown_word() {
+                return Err(parser.errors());
+            }
+
             if let Some(es) = ExprStatement::parse(parser).unwrap_or(None) {
                 return Ok(Some(Statement::ExprStatement(es)));
             }
@@ -1528,7 +1609,7 @@ impl ParseStatement for Statement {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseType for Type {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -1651,7 +1732,7 @@ impl ParseType for Type {
 ///////////////////////////////////////////////////////////////////////////////
 
 impl ParseTerm for Value {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -1842,7 +1923,7 @@ impl ParseTerm for Value {
 
 ///////////////////////////////////////////////////////////////////////////////
 
-fn get_item_by_keyword(parser: &mut Parser) -> Result<Option<Item>, Vec<CompilerError>> {
+fn get_item_by_keyword(parser: &mut Parser) -> Result<Option<Item>, Vec<Diagnostic>> {
     if let Some(cvd) = ConstVarDef::parse(parser)? {
         return Ok(Some(Item::ConstVarDef(cvd)));
     } else if let Some(ed) = EnumDef::parse(parser)? {
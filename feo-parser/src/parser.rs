@@ -1,4 +1,7 @@
+use std::{cell::RefCell, str::FromStr};
+
 use feo_ast::{
+    attribute::AttributeKind,
     expression::{
         ArithmeticOrLogicalExpr, ArithmeticOrLogicalOperatorKind, ArrayExpr, AssignmentExpr,
         BlockExpr, BreakExpr, ComparisonExpr, ComparisonOperatorKind, CompoundAssignOperatorKind,
@@ -11,48 +14,60 @@ use feo_ast::{
         UnderscoreExpr, UnwrapExpr, Value,
     },
     path::PathInExpr,
-    token::{Token, TokenStream},
+    token::Token,
 };
 use feo_error::{
-    error::CompilerError,
-    handler::{ErrorEmitted, Handler},
+    diagnostic::Diagnostic,
+    handler::{ErrorEmitted, Handler, SharedHandler},
+    lint::{self, LintLevel},
     parser_error::{ParserError, ParserErrorKind},
+    suggestion::{find_best_match, Applicability, Suggestion},
+    warning::{CompilerWarning, WarningKind},
 };
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
+    identifier,
     keyword::KeywordKind,
     literal::LiteralKind,
     punctuation::PuncKind,
-    span::{Position, Spanned},
-    Keyword,
+    span::{Position, Span, Spanned},
+    Identifier, Keyword, Punctuation,
 };
 
 use crate::{
     parse::{ParseExpr, ParseTerm},
     peek::{Peek, Peeker},
     precedence::Precedence,
+    token_buffer::{TokenBuffer, MAX_LOOKAHEAD},
 };
 
-/// Struct that stores a token stream and the current character index, and handles errors.
-pub struct Parser {
-    stream: TokenStream,
+/// Struct that stores a buffer of tokens (pulled from a token stream as they are needed)
+/// and the current token index, and handles errors.
+pub struct Parser<'a> {
+    buffer: RefCell<TokenBuffer<'a>>,
     pos: usize,
-    handler: Handler,
+    handler: SharedHandler,
+    declared_identifiers: Vec<String>, // names of items and `let` bindings parsed so far
 }
 
-impl Parser {
-    pub fn new(stream: TokenStream, handler: Handler) -> Self {
+impl<'a> Parser<'a> {
+    /// Create a parser over some stream of tokens, such as a `TokenStream` or a `Lexer`
+    /// (in which case the input is lexed lazily, as the parser advances).
+    /// Errors are logged to `handler` (a `Handler`, or a `SharedHandler` that the lexer
+    /// logs to as well).
+    pub fn new<I>(stream: I, handler: impl Into<SharedHandler>) -> Self
+    where
+        I: IntoIterator<Item = Token>,
+        I::IntoIter: 'a,
+    {
         Parser {
-            stream,
+            buffer: RefCell::new(TokenBuffer::new(stream)),
             pos: 0,
-            handler,
+            handler: handler.into(),
+            declared_identifiers: Vec::new(),
         }
     }
 
-    pub fn stream(&self) -> &TokenStream {
-        &self.stream
-    }
-
     pub fn pos(&self) -> usize {
         self.pos
     }
@@ -238,6 +253,10 @@ impl Parser {
 
             Some(Token::CharLit(c)) => Some(Expression::Literal(LiteralKind::Char(c))),
             Some(Token::StringLit(s)) => Some(Expression::Literal(LiteralKind::String(s))),
+            Some(Token::ByteLit(b)) => Some(Expression::Literal(LiteralKind::Byte(b))),
+            Some(Token::ByteStringLit(bs)) => {
+                Some(Expression::Literal(LiteralKind::ByteString(bs)))
+            }
             Some(Token::BoolLit(b)) => Some(Expression::Literal(LiteralKind::Bool(b))),
             Some(Token::IntLit(i)) => Some(Expression::Literal(LiteralKind::Int(i))),
             Some(Token::UIntLit(ui)) => Some(Expression::Literal(LiteralKind::UInt(ui))),
@@ -337,6 +356,81 @@ impl Parser {
                     }
                 }
 
+                PuncKind::AmpersandEquals => {
+                    if let Some(precedence) = Precedence::token_precedence(self) {
+                        let right = self.parse_expression(precedence)?;
+                        Some(Expression::OperatorExpr(OperatorExprKind::CompoundAssign(
+                            CompoundAssignmentExpr {
+                                assignee: Value::try_from(left).ok()?,
+                                operator: CompoundAssignOperatorKind::BitwiseAndAssign(p),
+                                new_value: Value::try_from(right).ok()?,
+                            },
+                        )))
+                    } else {
+                        None
+                    }
+                }
+
+                PuncKind::PipeEquals => {
+                    if let Some(precedence) = Precedence::token_precedence(self) {
+                        let right = self.parse_expression(precedence)?;
+                        Some(Expression::OperatorExpr(OperatorExprKind::CompoundAssign(
+                            CompoundAssignmentExpr {
+                                assignee: Value::try_from(left).ok()?,
+                                operator: CompoundAssignOperatorKind::BitwiseOrAssign(p),
+                                new_value: Value::try_from(right).ok()?,
+                            },
+                        )))
+                    } else {
+                        None
+                    }
+                }
+
+                PuncKind::CaretEquals => {
+                    if let Some(precedence) = Precedence::token_precedence(self) {
+                        let right = self.parse_expression(precedence)?;
+                        Some(Expression::OperatorExpr(OperatorExprKind::CompoundAssign(
+                            CompoundAssignmentExpr {
+                                assignee: Value::try_from(left).ok()?,
+                                operator: CompoundAssignOperatorKind::BitwiseXorAssign(p),
+                                new_value: Value::try_from(right).ok()?,
+                            },
+                        )))
+                    } else {
+                        None
+                    }
+                }
+
+                PuncKind::DblLessThanEquals => {
+                    if let Some(precedence) = Precedence::token_precedence(self) {
+                        let right = self.parse_expression(precedence)?;
+                        Some(Expression::OperatorExpr(OperatorExprKind::CompoundAssign(
+                            CompoundAssignmentExpr {
+                                assignee: Value::try_from(left).ok()?,
+                                operator: CompoundAssignOperatorKind::ShiftLeftAssign(p),
+                                new_value: Value::try_from(right).ok()?,
+                            },
+                        )))
+                    } else {
+                        None
+                    }
+                }
+
+                PuncKind::DblGreaterThanEquals => {
+                    if let Some(precedence) = Precedence::token_precedence(self) {
+                        let right = self.parse_expression(precedence)?;
+                        Some(Expression::OperatorExpr(OperatorExprKind::CompoundAssign(
+                            CompoundAssignmentExpr {
+                                assignee: Value::try_from(left).ok()?,
+                                operator: CompoundAssignOperatorKind::ShiftRightAssign(p),
+                                new_value: Value::try_from(right).ok()?,
+                            },
+                        )))
+                    } else {
+                        None
+                    }
+                }
+
                 PuncKind::PlusEquals => {
                     if let Some(precedence) = Precedence::token_precedence(self) {
                         let right = self.parse_expression(precedence)?;
@@ -709,67 +803,327 @@ impl Parser {
 
     /// Return the current token.
     pub fn current_token(&self) -> Option<Token> {
-        self.stream.tokens().get(self.pos).cloned()
+        self.buffer.borrow_mut().get(self.pos)
     }
 
     /// Advance the parser and return the current token.
+    /// Tokens before the new previous token are released from the buffer.
     pub fn next_token(&mut self) -> Option<Token> {
         let token = self.current_token();
         if token.is_some() {
             self.pos += 1;
+            self.buffer.get_mut().release(self.pos - 1);
         }
 
         token
     }
 
+    /// Run a parse function, and move the parser back to the token that it started on if the
+    /// function does not find its node (i.e., returns `Ok(None)` after consuming tokens,
+    /// such as the `x` and `.` of `x.0` when looking for a method call).
+    pub fn try_parse<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Parser) -> Result<Option<T>, Vec<Diagnostic>>,
+    ) -> Result<Option<T>, Vec<Diagnostic>> {
+        let start = self.pos;
+        let prev_hold = self.hold_from(start);
+
+        let result = parse(self);
+
+        if let Ok(None) = result {
+            self.pos = start;
+        }
+
+        self.buffer.get_mut().hold(prev_hold);
+
+        result
+    }
+
+    /// Parse the delimited group that starts at the current token with `parse`, and if that
+    /// fails, skip the whole group (leaving the parser on its close delimiter), so that the
+    /// caller can go on parsing after it, however deep in the group the error was.
+    /// The tokens of the group are kept in the buffer until `parse` returns.
+    pub fn parse_group<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Parser) -> Result<T, Vec<Diagnostic>>,
+    ) -> Result<T, Vec<Diagnostic>> {
+        let start = self.pos;
+        let prev_hold = self.hold_from(start);
+
+        let result = parse(self);
+
+        if result.is_err() {
+            self.pos = start;
+            self.skip_delimited_group();
+        }
+
+        self.buffer.get_mut().hold(prev_hold);
+
+        result
+    }
+
+    // keep the tokens from `start` in the buffer, and return the previous hold (to restore)
+    fn hold_from(&mut self, start: usize) -> Option<usize> {
+        // keep the previous token too, as errors after going back are logged relative to it
+        // (an outer hold may already keep an earlier token)
+        let buffer = self.buffer.get_mut();
+        let prev_hold = buffer.hold(None);
+        buffer.hold(Some(prev_hold.map_or(start.saturating_sub(1), |h| {
+            h.min(start.saturating_sub(1))
+        })));
+
+        prev_hold
+    }
+
     pub fn peek_num_tokens_ahead(&self, num_tokens: usize) -> Option<Token> {
-        self.stream.tokens().get(self.pos + num_tokens).cloned()
+        debug_assert!(
+            num_tokens <= MAX_LOOKAHEAD,
+            "lookahead exceeds `MAX_LOOKAHEAD`"
+        );
+        self.buffer.borrow_mut().get(self.pos + num_tokens)
     }
 
     /// Return the previous token.
     pub fn previous_token(&mut self) -> Option<Token> {
         if self.pos > 0 {
-            self.stream.tokens().get(self.pos - 1).cloned()
+            self.buffer.get_mut().get(self.pos - 1)
         } else {
             None
         }
     }
 
+    /// Skip over the balanced delimiter group that starts at the current token (e.g., `( .. )`),
+    /// leaving the parser on its matching close delimiter (or at the end of the stream if the
+    /// group is unclosed). Used to resynchronise after an error inside a group.
+    /// Return `false` (without advancing) if the current token is not an open delimiter.
+    pub fn skip_delimited_group(&mut self) -> bool {
+        match self.current_token() {
+            Some(Token::Delim(d)) if matches!(d.delim.1, DelimOrientation::Open) => (),
+            _ => return false,
+        }
+
+        let mut depth: usize = 0;
+
+        while let Some(t) = self.current_token() {
+            if let Token::Delim(d) = t {
+                match d.delim.1 {
+                    DelimOrientation::Open => depth += 1,
+                    DelimOrientation::Close => depth -= 1,
+                }
+            }
+
+            if depth == 0 {
+                break;
+            }
+
+            self.next_token();
+        }
+
+        true
+    }
+
     /// Peek at the current `T` and return it if it exists (without advancing) or return `None`.
     pub fn peek_current<T: Peek>(&self) -> Option<T> {
-        Peeker::with(&self.stream().tokens(), self.pos)
+        self.peek_ahead(0)
     }
 
     /// Peek at the next `T` and return it if it exists (without advancing) or return `None`.
     pub fn peek_next<T: Peek>(&self) -> Option<T> {
-        Peeker::with(&self.stream().tokens(), self.pos + 1)
+        self.peek_ahead(1)
     }
 
     /// Peek at the `T` at `num_tokens` index and return it if it exists (without advancing)
     /// or return `None`.
     pub fn peek_ahead<T: Peek>(&self, offset: usize) -> Option<T> {
-        Peeker::with(&self.stream().tokens(), self.pos + offset)
+        debug_assert!(offset <= MAX_LOOKAHEAD, "lookahead exceeds `MAX_LOOKAHEAD`");
+        Peeker::with(self.buffer.borrow_mut().tokens_from(self.pos + offset), 0)
     }
 
-    /// Push `ParserError` to the `Handler`.
+    /// Push `ParserError` to the `Handler`, with a suggestion to insert the expected token
+    /// after the previous token if it is a missing delimiter, `;` or `,`.
+    /// The error is logged at the current token (or at the end of the previous token if
+    /// the end of the stream was reached).
     /// Return `ErrorEmitted` just to confirm that the action happened.
     pub fn log_error(&self, error_kind: ParserErrorKind) -> ErrorEmitted {
+        let prev_span = if self.pos > 0 {
+            self.buffer
+                .borrow_mut()
+                .get(self.pos - 1)
+                .map(|t| (t.span(), is_comment(&t)))
+        } else {
+            None
+        };
+
+        let position = match (self.current_token(), prev_span) {
+            (Some(t), _) => t.span().start_pos(),
+            (None, Some((span, _))) => Position::new(span.source_id(), span.end()),
+            (None, None) => panic!("PositionError: token not found"),
+        };
+
+        let suggestion = match (missing_token(&error_kind), prev_span) {
+            // an insertion after a line comment would end up in the comment
+            (Some((text, applicability)), Some((span, false))) => Some(Suggestion::insertion(
+                span.source_id(),
+                span.end(),
+                text,
+                applicability,
+            )),
+            _ => None,
+        };
+
         let err = ParserError {
             error_kind,
-            position: Position::new(
-                &self.stream.span().source(),
-                self.stream()
-                    .tokens()
-                    .get(self.pos)
-                    .expect("PositionError: token not found")
-                    .span()
-                    .start(),
-            ),
+            position,
         };
 
-        self.handler.emit_err(CompilerError::Parser(err))
+        let diagnostic = Diagnostic::from(err);
+
+        match suggestion {
+            Some(s) => self.handler.emit_diagnostic(diagnostic.with_suggestion(s)),
+            None => self.handler.emit_diagnostic(diagnostic),
+        }
+    }
+
+    /// Record the name of an item or `let` binding, so that it can be suggested for
+    /// misspelled words.
+    pub fn declare_identifier(&mut self, id: &Identifier) {
+        if !self.declared_identifiers.contains(&id.name) {
+            self.declared_identifiers.push(id.name.clone());
+        }
     }
-    pub fn errors(&self) -> Vec<CompilerError> {
-        self.handler.clone().get_inner().0
+
+    /// Check whether the current token is a word that cannot start an item or statement,
+    /// i.e., an identifier that is followed by another identifier (e.g., `fn foo`), or a path
+    /// root from another language that is followed by `::` (e.g., `crate::foo`), and log
+    /// an error that suggests the Feo keyword for a keyword from another language, or else
+    /// the closest keyword or declared identifier.
+    /// Return `true` if an error was logged.
+    pub fn check_unknown_word(&self) -> bool {
+        let Some(id) = self.peek_current::<Identifier>() else {
+            return false;
+        };
+
+        let is_foreign_path_root = foreign_path_root(&id.name).is_some()
+            && matches!(
+                self.peek_next::<Punctuation>(),
+                Some(Punctuation {
+                    punc_kind: PuncKind::DblColon,
+                    ..
+                })
+            );
+
+        // contextual keywords are lexed as identifiers, and may start an item
+        if id.is_raw
+            || identifier::is_contextual_keyword(&id.name)
+            || (self.peek_next::<Identifier>().is_none() && !is_foreign_path_root)
+        {
+            return false;
+        }
+
+        let error_kind = match foreign_keyword(&id.name) {
+            Some(replacement) => ParserErrorKind::ForeignKeyword {
+                found: id.name.clone(),
+                replacement: replacement.to_string(),
+            },
+            None => {
+                let keywords = KeywordKind::ALL.iter().map(|k| k.as_str());
+                let identifiers = self.declared_identifiers.iter().map(|i| i.as_str());
+
+                ParserErrorKind::UnknownWord {
+                    found: id.name.clone(),
+                    suggestion: find_best_match(&id.name, keywords.chain(identifiers))
+                        .map(|s| s.to_string()),
+                }
+            }
+        };
+
+        self.log_error(error_kind);
+
+        true
+    }
+
+    /// Set the lint levels of the lint attributes (e.g., `#[deny(unnecessary_raw_identifier)]`) in the
+    /// source code covered by `span` (i.e., the item or file that the attributes are on),
+    /// warning about lint names that do not exist.
+    pub fn set_lint_levels<'b>(
+        &self,
+        attributes: impl IntoIterator<Item = &'b AttributeKind>,
+        span: Span,
+    ) {
+        for attr in attributes {
+            if let AttributeKind::Lint(la) = attr {
+                // the level was checked when the attribute was parsed
+                let Ok(level) = LintLevel::from_str(&la.level.name) else {
+                    continue;
+                };
+
+                for id in la.lints.iter() {
+                    match lint::find_lint(&id.name) {
+                        Some(l) => self.handler.set_lint_level(span, l, level),
+                        None => self.handler.emit_warn(CompilerWarning::new(
+                            WarningKind::UnknownLint {
+                                name: id.name.clone(),
+                            },
+                            id.span(),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn errors(&self) -> Vec<Diagnostic> {
+        self.handler.errors()
+    }
+
+    /// Return the `Handler` that errors are logged to (e.g., to render them).
+    pub fn handler(&self) -> &Handler {
+        &self.handler
+    }
+}
+
+// token that an error says is missing, and how likely it is that inserting it fixes the
+// error (a missing close delimiter often means that something else is wrong inside the
+// delimiters, e.g., a missing `,` in `foo(a b)`)
+fn missing_token(error_kind: &ParserErrorKind) -> Option<(&str, Applicability)> {
+    match error_kind {
+        ParserErrorKind::MissingDelimiter { delim } => {
+            Some((delim.as_str(), Applicability::MachineApplicable))
+        }
+        ParserErrorKind::UnexpectedToken { expected, .. } => match expected.as_str() {
+            "`;`" => Some((";", Applicability::MachineApplicable)),
+            "`,`" => Some((",", Applicability::MachineApplicable)),
+            "`)`" => Some((")", Applicability::MaybeIncorrect)),
+            "`]`" => Some(("]", Applicability::MaybeIncorrect)),
+            "`}`" => Some(("}", Applicability::MaybeIncorrect)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_comment(token: &Token) -> bool {
+    matches!(token, Token::Comment(_) | Token::DocComment(_))
+}
+
+/// Return the Feo keyword for a keyword from another language (e.g., Rust or Solidity),
+/// or `None` if it has no equivalent.
+pub fn foreign_keyword(word: &str) -> Option<&'static str> {
+    match word {
+        "fn" | "function" => Some("func"),
+        "use" => Some("import"),
+        "mod" => Some("module"),
+        "crate" => Some("package"),
+        _ => None,
+    }
+}
+
+/// Return the Feo keyword for a path root from another language (e.g., `crate`),
+/// or `None` if the word is not one.
+/// Other foreign keywords (e.g., `use`) are valid names for path segments.
+pub fn foreign_path_root(word: &str) -> Option<&'static str> {
+    match word {
+        "crate" => foreign_keyword(word),
+        _ => None,
     }
 }
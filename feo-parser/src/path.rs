@@ -2,12 +2,12 @@ use feo_ast::{
     path::{PathIdenSegmentKind, PathInExpr, PathType, SimplePath, SimplePathSegmentKind},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Identifier, Keyword, Punctuation};
 
 use crate::{
     parse::ParseTerm,
-    parser::Parser,
+    parser::{self, Parser},
     peek::{Peek, Peeker},
     test_utils::{self, LogMsgType},
 };
@@ -33,12 +33,14 @@ impl Peek for SimplePathSegmentKind {
 }
 
 impl ParseTerm for SimplePath {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
         let mut subsequent_segments: Vec<SimplePathSegmentKind> = Vec::new();
 
+        check_foreign_path_root(parser);
+
         if let Some(first_segment) = parser.peek_current::<SimplePathSegmentKind>() {
             while let Some(Punctuation {
                 punc_kind: PuncKind::DblColon,
@@ -99,12 +101,14 @@ impl Peek for PathIdenSegmentKind {
 }
 
 impl ParseTerm for PathInExpr {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
         let mut subsequent_segments: Vec<PathIdenSegmentKind> = Vec::new();
 
+        check_foreign_path_root(parser);
+
         if let Some(first_segment) = parser.peek_current::<PathIdenSegmentKind>() {
             test_utils::log_msg(LogMsgType::Enter, "path in expression", parser);
 
@@ -146,12 +150,14 @@ impl ParseTerm for PathInExpr {
 }
 
 impl ParseTerm for PathType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
         let mut subsequent_segments: Vec<PathIdenSegmentKind> = Vec::new();
 
+        check_foreign_path_root(parser);
+
         if let Some(first_segment) = parser.peek_current::<PathIdenSegmentKind>() {
             test_utils::log_msg(LogMsgType::Enter, "path type", parser);
 
@@ -192,13 +198,38 @@ impl ParseTerm for PathType {
     }
 }
 
+/// Log an error if a path starts with a root segment from another language
+/// (e.g., `crate::foo` instead of `package::foo`).
+/// The path is still parsed, with the word as an ordinary identifier segment.
+fn check_foreign_path_root(parser: &Parser) {
+    if let Some(id) = parser.peek_current::<Identifier>() {
+        if id.is_raw {
+            return;
+        }
+
+        if let (
+            Some(replacement),
+            Some(Punctuation {
+                punc_kind: PuncKind::DblColon,
+                ..
+            }),
+        ) = (parser::foreign_path_root(&id.name), parser.peek_next())
+        {
+            parser.log_error(ParserErrorKind::ForeignKeyword {
+                found: id.name,
+                replacement: replacement.to_string(),
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
     #[test]
-    fn parse_path_simple() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_simple() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"package::some_module::SomeObject"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -209,7 +240,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_path_expr() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_expr() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"Self::method"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -220,7 +251,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_path_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_path_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"SomeType"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
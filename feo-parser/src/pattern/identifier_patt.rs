@@ -1,11 +1,11 @@
 use feo_ast::pattern::IdentifierPatt;
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{keyword::KeywordKind, Identifier, Keyword};
 
 use crate::{parse::ParsePatt, parser::Parser};
 
 impl ParsePatt for IdentifierPatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -49,7 +49,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_identifier_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_identifier_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"ref mut foo"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
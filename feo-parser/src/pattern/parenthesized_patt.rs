@@ -2,7 +2,7 @@ use feo_ast::{
     pattern::{ParenthesizedPatt, Pattern},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -11,7 +11,7 @@ use feo_types::{
 use crate::{parse::ParsePatt, parser::Parser};
 
 impl ParsePatt for ParenthesizedPatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -63,7 +63,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_parenthesized_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_parenthesized_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(x)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -2,7 +2,7 @@ use feo_ast::{
     pattern::{RangeFromPatt, RangeInclusivePatt, RangePattBound, RangeToInclusivePatt},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{literal::LiteralKind, punctuation::PuncKind, Punctuation};
 
 use crate::{
@@ -32,7 +32,7 @@ impl Peek for RangePattBound {
 }
 
 impl ParsePatt for RangeFromPatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -60,7 +60,7 @@ impl ParsePatt for RangeFromPatt {
 }
 
 impl ParsePatt for RangeInclusivePatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -99,7 +99,7 @@ impl ParsePatt for RangeInclusivePatt {
 }
 
 impl ParsePatt for RangeToInclusivePatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -141,7 +141,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_range_from_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_from_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1.."#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -153,7 +153,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_inclusive_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_inclusive_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"1..=10"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -165,7 +165,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_range_to_inclusive_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_range_to_inclusive_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"..=10"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -2,13 +2,13 @@ use feo_ast::{
     pattern::{Pattern, ReferencePatt},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Keyword, Punctuation};
 
 use crate::{parse::ParsePatt, parser::Parser};
 
 impl ParsePatt for ReferencePatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -59,7 +59,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_reference_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_reference_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"&mut parser"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -2,7 +2,7 @@ use feo_ast::{
     pattern::{Pattern, StructPatt, StructPattField, TupleStructPatt, TupleStructPattField},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     punctuation::PuncKind,
@@ -16,7 +16,7 @@ use crate::{
 };
 
 impl ParseTerm for StructPattField {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -60,7 +60,7 @@ impl ParseTerm for StructPattField {
 }
 
 impl ParsePatt for StructPatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -114,7 +114,7 @@ impl ParsePatt for StructPatt {
 }
 
 impl ParseTerm for TupleStructPattField {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -127,7 +127,7 @@ impl ParseTerm for TupleStructPattField {
 }
 
 impl ParsePatt for TupleStructPatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -188,7 +188,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_struct_patt_field() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_patt_field() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
             #[abstract]
             #[unsafe]
@@ -204,7 +204,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_struct_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_struct_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         SomeStruct {
             foo: "a",
@@ -220,7 +220,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_tuple_struct_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_struct_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"SomeStruct("a", 1, x)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -2,7 +2,7 @@ use feo_ast::{
     pattern::{Pattern, TuplePatt, TuplePattElement},
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -15,7 +15,7 @@ use crate::{
 };
 
 impl ParseTerm for TuplePattElement {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -28,7 +28,7 @@ impl ParseTerm for TuplePattElement {
 }
 
 impl ParsePatt for TuplePatt {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -84,7 +84,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_tuple_patt() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_patt() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(1, "a", x)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
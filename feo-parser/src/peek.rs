@@ -53,6 +53,24 @@ impl<'a> Peeker<'a> {
         }
     }
 
+    /// Peek for a byte literal.
+    /// Return it if it exists, or return `Self` (i.e., do nothing)
+    fn peek_byte_lit(&self) -> Result<Literal<u8>, Self> {
+        match self.peek_token() {
+            Some(Token::ByteLit(b)) => Ok(b),
+            _ => Err(*self),
+        }
+    }
+
+    /// Peek for a byte string literal.
+    /// Return it if it exists, or return `Self` (i.e., do nothing)
+    fn peek_byte_string_lit(&self) -> Result<Literal<Vec<u8>>, Self> {
+        match self.peek_token() {
+            Some(Token::ByteStringLit(bs)) => Ok(bs),
+            _ => Err(*self),
+        }
+    }
+
     /// Peek for a bool literal.
     /// Return it if it exists, or return `Self` (i.e., do nothing)
     fn peek_bool_lit(&self) -> Result<Literal<bool>, Self> {
@@ -161,6 +179,24 @@ impl Peek for Literal<String> {
     }
 }
 
+impl Peek for Literal<u8> {
+    fn peek(peeker: &Peeker<'_>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        peeker.peek_byte_lit().ok()
+    }
+}
+
+impl Peek for Literal<Vec<u8>> {
+    fn peek(peeker: &Peeker<'_>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        peeker.peek_byte_string_lit().ok()
+    }
+}
+
 impl Peek for Literal<bool> {
     fn peek(peeker: &Peeker<'_>) -> Option<Self>
     where
@@ -201,7 +201,14 @@ impl Precedence {
                             | PuncKind::MinusEquals
                             | PuncKind::AsteriskEquals
                             | PuncKind::ForwardSlashEquals
-                            | PuncKind::PercentEquals => Some(Precedence::CompoundAssignment),
+                            | PuncKind::PercentEquals
+                            | PuncKind::AmpersandEquals
+                            | PuncKind::PipeEquals
+                            | PuncKind::CaretEquals
+                            | PuncKind::DblLessThanEquals
+                            | PuncKind::DblGreaterThanEquals => {
+                                Some(Precedence::CompoundAssignment)
+                            }
 
                             PuncKind::DblDot | PuncKind::DotDotEquals => Some(Precedence::Range),
 
@@ -314,9 +321,11 @@ impl Precedence {
                 None => Some(Precedence::Literal),
             },
 
-            Some(Token::CharLit(_)) | Some(Token::StringLit(_)) | Some(Token::BoolLit(_)) => {
-                Some(Precedence::Literal)
-            }
+            Some(Token::CharLit(_))
+            | Some(Token::StringLit(_))
+            | Some(Token::ByteLit(_))
+            | Some(Token::ByteStringLit(_))
+            | Some(Token::BoolLit(_)) => Some(Precedence::Literal),
 
             _ => Some(Precedence::Lowest),
         }
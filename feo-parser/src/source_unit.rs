@@ -0,0 +1,362 @@
+use feo_ast::{
+    attribute::InnerAttr,
+    item::Item,
+    source_unit::{ProgramHeader, ProgramKind, SourceUnit},
+    token::Token,
+};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
+use feo_types::{
+    delimiter::{DelimKind, DelimOrientation},
+    keyword::KeywordKind,
+    punctuation::PuncKind,
+    span::Span,
+    Delimiter, Identifier, Keyword, Punctuation,
+};
+
+use crate::{
+    parse::{ParseItem, ParseTerm},
+    parser::Parser,
+    peek::{Peek, Peeker},
+    test_utils::{self, LogMsgType},
+    utils,
+};
+
+impl Peek for ProgramKind {
+    fn peek(peeker: &Peeker<'_>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        // contextual keywords are lexed as identifiers
+        let keyword = Identifier::peek(peeker).and_then(|id| id.as_contextual_keyword())?;
+
+        match &keyword.keyword_kind {
+            KeywordKind::KwContract => Some(ProgramKind::KwContract(keyword)),
+            KeywordKind::KwLibrary => Some(ProgramKind::KwLibrary(keyword)),
+            KeywordKind::KwScript => Some(ProgramKind::KwScript(keyword)),
+            _ => None,
+        }
+    }
+}
+
+impl ParseTerm for ProgramHeader {
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
+    where
+        Self: Sized,
+    {
+        // a program kind that is not followed by `;` is an ordinary identifier
+        if let (
+            Some(program_kind),
+            Some(
+                semicolon @ Punctuation {
+                    punc_kind: PuncKind::Semicolon,
+                    ..
+                },
+            ),
+        ) = (parser.peek_current::<ProgramKind>(), parser.peek_next())
+        {
+            test_utils::log_msg(LogMsgType::Detect, "program header", parser);
+
+            parser.next_token();
+
+            return Ok(Some(ProgramHeader {
+                program_kind,
+                semicolon,
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Parse the tokens of a whole file, whose source code is covered by `span`.
+/// Errors are logged to the parser's `Handler`; after an item that fails to parse, the parser
+/// skips to the next token that can start an item, so that every broken item is reported and
+/// the items around it are kept.
+pub(crate) fn parse_source_unit(parser: &mut Parser, span: Span) -> SourceUnit {
+    let inner_attributes_opt = utils::get_attributes::<InnerAttr>(parser).unwrap_or(None);
+
+    if let Some(attributes) = &inner_attributes_opt {
+        parser.set_lint_levels(attributes.iter().map(|a| &a.attribute), span);
+    }
+
+    let program_header_opt = match ProgramHeader::parse(parser).unwrap_or(None) {
+        Some(ph) => {
+            parser.next_token();
+            Some(ph)
+        }
+        None => None,
+    };
+
+    let mut items: Vec<Item> = Vec::new();
+
+    while parser.current_token().is_some() && !parser.handler().error_limit_reached() {
+        match Item::parse(parser) {
+            Ok(Some(i)) => {
+                items.push(i);
+                parser.next_token();
+            }
+            Ok(None) => {
+                parser.log_error(ParserErrorKind::UnexpectedToken {
+                    expected: "item".to_string(),
+                    found: parser.current_token().unwrap_or(Token::EOF).to_string(),
+                });
+
+                skip_to_next_item(parser);
+            }
+            Err(_) => skip_to_next_item(parser),
+        }
+    }
+
+    SourceUnit {
+        inner_attributes_opt,
+        program_header_opt,
+        items,
+        span,
+    }
+}
+
+/// Advance the parser past the current token (or delimited group) and any tokens after it,
+/// up to the next token that can start an item, or the end of the stream.
+fn skip_to_next_item(parser: &mut Parser) {
+    loop {
+        parser.skip_delimited_group();
+        parser.next_token();
+
+        match parser.current_token() {
+            None => break,
+            Some(Token::Punc(Punctuation {
+                punc_kind: PuncKind::HashSign,
+                ..
+            })) => break,
+            Some(Token::Keyword(k)) if is_item_keyword(&k) => break,
+            Some(Token::Identifier(id)) if is_contextual_item_start(parser, &id) => break,
+            _ => (),
+        }
+    }
+}
+
+// contextual keywords are lexed as identifiers, so they only start an item where an item
+// could follow them (e.g., `abi Foo {`, `storage {` or `export func`, but not `storage.x`)
+fn is_contextual_item_start(parser: &Parser, id: &Identifier) -> bool {
+    let Some(keyword) = id.as_contextual_keyword() else {
+        return false;
+    };
+
+    let is_item_keyword = matches!(
+        keyword.keyword_kind,
+        KeywordKind::KwAbi
+            | KeywordKind::KwContract
+            | KeywordKind::KwExport
+            | KeywordKind::KwExtern
+            | KeywordKind::KwLibrary
+            | KeywordKind::KwScript
+            | KeywordKind::KwStorage
+    );
+
+    is_item_keyword
+        && matches!(
+            parser.peek_num_tokens_ahead(1),
+            Some(Token::Identifier(_) | Token::Keyword(_))
+                | Some(Token::Delim(Delimiter {
+                    delim: (DelimKind::Brace, DelimOrientation::Open),
+                    ..
+                }))
+                | Some(Token::Punc(Punctuation {
+                    punc_kind: PuncKind::Semicolon,
+                    ..
+                }))
+        )
+}
+
+fn is_item_keyword(keyword: &Keyword) -> bool {
+    matches!(
+        keyword.keyword_kind,
+        KeywordKind::KwConst
+            | KeywordKind::KwEnum
+            | KeywordKind::KwFunc
+            | KeywordKind::KwImpl
+            | KeywordKind::KwImport
+            | KeywordKind::KwModule
+            | KeywordKind::KwPub
+            | KeywordKind::KwStatic
+            | KeywordKind::KwStruct
+            | KeywordKind::KwTrait
+            | KeywordKind::KwType
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_ast::expression::Expression;
+    use feo_error::handler::Handler;
+    use feo_types::{source_map::SourceMap, span::Spanned};
+
+    use crate::{parse_expr, parse_file, parse_item, parse_pattern, parse_type};
+
+    #[test]
+    fn parse_source_unit() {
+        let source_code = r#"
+        #![allow(unnecessary_raw_identifier)]
+
+        contract;
+
+        import package::some_module::SomeObject;
+
+        const r#X: u64 = 2;
+
+        struct Foo {
+            bar: u64,
+        }
+        "#;
+
+        let handler = Handler::default();
+
+        let mut source_map = SourceMap::new();
+
+        let source_unit = parse_file(&mut source_map, source_code, "source_file.feo", &handler);
+
+        assert!(handler.errors().is_empty(), "{:#?}", handler.errors());
+        // the inner attribute allows the lexer's warning about `r#X` in the whole file
+        assert!(handler.warnings().is_empty(), "{:#?}", handler.warnings());
+        assert_eq!(source_unit.span().as_str(&source_map), source_code);
+        assert_eq!(source_unit.inner_attributes_opt.map(|a| a.len()), Some(1));
+        assert!(source_unit.program_header_opt.is_some());
+        assert_eq!(source_unit.items.len(), 3);
+    }
+
+    #[test]
+    fn parse_source_unit_with_errors() {
+        let source_code = r#"
+        fn foo() {}
+
+        const X: u64 = 2;
+
+        strcut Foo {}
+
+        const Y: u64 = 3;
+        "#;
+
+        let handler = Handler::default();
+
+        let mut source_map = SourceMap::new();
+
+        let source_unit = parse_file(&mut source_map, source_code, "broken.feo", &handler);
+
+        let codes = handler
+            .errors()
+            .iter()
+            .filter_map(|e| e.code.clone())
+            .collect::<Vec<String>>();
+
+        // both broken items are reported, and the items after them are kept
+        assert_eq!(codes, ["E0216", "E0217"]);
+        assert_eq!(source_unit.items.len(), 2);
+    }
+
+    #[test]
+    fn recover_at_contextual_item_keywords() {
+        let source_code = r#"
+        strcut X {}
+
+        abi Foo {
+            func bar();
+        }
+
+        storage {
+            balance: u64 = 0,
+        }
+
+        const Y: u64 = 3;
+        "#;
+
+        let handler = Handler::default();
+
+        let mut source_map = SourceMap::new();
+
+        let source_unit = parse_file(&mut source_map, source_code, "contextual.feo", &handler);
+
+        let lines = handler
+            .errors()
+            .iter()
+            .map(|e| e.line_col(&source_map).0)
+            .collect::<Vec<usize>>();
+
+        // the `abi` and `storage` items are not swallowed by the recovery from the first error
+        // (but are reported, as the parser does not support them yet)
+        assert_eq!(lines, [2, 4, 8]);
+        assert_eq!(source_unit.items.len(), 1);
+
+        // a contextual keyword that does not start an item is an ordinary identifier
+        let handler = Handler::default();
+
+        parse_file(
+            &mut source_map,
+            "strcut X { storage.x }
+const Y: u64 = 3;",
+            "contextual_identifier.feo",
+            &handler,
+        );
+
+        assert_eq!(handler.errors().len(), 1);
+    }
+
+    #[test]
+    fn parse_source_unit_with_lex_errors() {
+        let source_code = "const C: char = 'ab';\nconst X: u64 = 2;";
+
+        let handler = Handler::default();
+
+        parse_file(
+            &mut SourceMap::new(),
+            source_code,
+            "lex_errors.feo",
+            &handler,
+        );
+
+        // the lexer logs to the caller's `Handler` while the parser pulls tokens from it
+        assert!(handler
+            .errors()
+            .iter()
+            .any(|e| e.code.as_deref() == Some("E0102")));
+    }
+
+    #[test]
+    fn parse_fragments() {
+        let handler = Handler::default();
+
+        let mut source_map = SourceMap::new();
+
+        assert!(parse_expr(&mut source_map, "x + 2", &handler).is_some());
+        assert!(parse_type(&mut source_map, "[u64; 4]", &handler).is_some());
+        assert!(parse_pattern(&mut source_map, "SomeStruct(a, _)", &handler).is_some());
+        assert!(parse_item(&mut source_map, "const X: u64 = 2;", &handler).is_some());
+        assert!(handler.errors().is_empty(), "{:#?}", handler.errors());
+
+        // the whole fragment must be used
+        assert!(parse_expr(&mut source_map, "x + 2 y", &handler).is_none());
+        assert_eq!(handler.errors().len(), 1);
+
+        // a fragment that is not the node that was asked for is an error too
+        let handler = Handler::default();
+
+        assert!(parse_type(&mut source_map, "", &handler).is_none());
+        assert!(parse_item(&mut source_map, "x + 2", &handler).is_none());
+
+        let messages = handler
+            .errors()
+            .iter()
+            .map(|e| e.primary.message.clone())
+            .collect::<Vec<String>>();
+
+        assert_eq!(messages, ["expected type", "expected item"]);
+
+        // every call parses its own file, so identical fragments do not share a `SourceId`
+        let span = |e: Expression| e.span();
+
+        let first = parse_expr(&mut source_map, "y + 3", &handler).map(span);
+        let second = parse_expr(&mut source_map, "y + 3", &handler).map(span);
+
+        assert_ne!(first.map(|s| s.source_id()), second.map(|s| s.source_id()));
+        assert_eq!(first.map(|s| s.as_str(&source_map)), Some("y + 3"));
+    }
+}
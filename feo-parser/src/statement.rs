@@ -5,7 +5,7 @@ use feo_ast::{
     token::Token,
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Keyword, Punctuation};
 
 use crate::{
@@ -16,7 +16,7 @@ use crate::{
 };
 
 impl ParseStatement for ExprStatement {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -50,7 +50,7 @@ impl ParseStatement for ExprStatement {
 }
 
 impl ParseStatement for LetStatement {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -66,6 +66,10 @@ impl ParseStatement for LetStatement {
             parser.next_token();
 
             if let Some(pattern) = Pattern::parse(parser)? {
+                if let Pattern::IdentifierPatt(idp) = &pattern {
+                    parser.declare_identifier(&idp.name);
+                }
+
                 parser.next_token();
 
                 let type_ann_opt = if let Some(Punctuation {
@@ -141,11 +145,16 @@ impl ParseStatement for LetStatement {
 
 #[cfg(test)]
 mod tests {
+    use feo_ast::{item::Item, statement::Statement};
+    use feo_error::suggestion::{apply_suggestions, edit_distance, find_best_match};
+    use feo_types::source_map::SourceMap;
+
+    use crate::parse::ParseItem;
 
     use super::*;
 
     #[test]
-    fn parse_expr_statement() -> Result<(), Vec<CompilerError>> {
+    fn parse_expr_statement() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"x + 2"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -157,7 +166,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_let_statement() -> Result<(), Vec<CompilerError>> {
+    fn parse_let_statement() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"let x = 12 * 4;"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -167,4 +176,77 @@ mod tests {
 
         Ok(println!("{:#?}", let_statement))
     }
+
+    #[test]
+    fn fix_missing_semicolon() {
+        let mut source_map = SourceMap::new();
+
+        let source_code = "let x = 12 * 4\nlet y = x;";
+
+        let mut parser =
+            test_utils::get_parser_with_source_map(&mut source_map, source_code, false)
+                .expect("unable to lex");
+
+        let errors = LetStatement::parse(&mut parser).expect_err("missing `;` not reported");
+
+        assert_eq!(errors[0].line_col(&source_map), (2, 1));
+        assert_eq!(
+            apply_suggestions(source_code, &errors),
+            "let x = 12 * 4;\nlet y = x;"
+        );
+    }
+
+    #[test]
+    fn suggest_feo_keywords() {
+        let fixes = [
+            ("fn foo() {}", "func foo() {}"),
+            ("function foo() {}", "func foo() {}"),
+            ("use foo::Bar;", "import foo::Bar;"),
+            ("mod foo {}", "module foo {}"),
+            ("import crate::foo::Bar;", "import package::foo::Bar;"),
+            ("crate::foo();", "package::foo();"),
+        ];
+
+        for (source_code, fixed) in fixes {
+            let mut parser = test_utils::get_parser(source_code, false).expect("unable to lex");
+
+            let errors = match Item::parse(&mut parser) {
+                Ok(_) => parser.errors(),
+                Err(errors) => errors,
+            };
+
+            assert_eq!(errors[0].code.as_deref(), Some("E0216"), "{}", source_code);
+            assert_eq!(apply_suggestions(source_code, &errors), fixed);
+        }
+    }
+
+    #[test]
+    fn suggest_similar_words() {
+        let source_code = "strcut Foo {}";
+
+        let mut parser = test_utils::get_parser(source_code, false).expect("unable to lex");
+
+        let errors = Item::parse(&mut parser).expect_err("unknown word not reported");
+
+        assert_eq!(errors[0].code.as_deref(), Some("E0217"));
+        assert_eq!(errors[0].primary.message, "did you mean `struct`?");
+
+        // declared identifiers are suggested too
+        let source_code = "let counter = 1;\ncoutner x;";
+
+        let mut parser = test_utils::get_parser(source_code, false).expect("unable to lex");
+
+        LetStatement::parse(&mut parser)
+            .expect("unable to parse let statement")
+            .expect("let statement not found");
+
+        let errors = Statement::parse(&mut parser).expect_err("unknown word not reported");
+
+        assert_eq!(errors[0].primary.message, "did you mean `counter`?");
+
+        assert_eq!(edit_distance("strcut", "struct"), 1);
+        assert_eq!(edit_distance("", "func"), 4);
+        assert_eq!(find_best_match("Func", ["func"]), Some("func"));
+        assert_eq!(find_best_match("frobnicate", ["func", "for"]), None);
+    }
 }
@@ -1,18 +1,34 @@
-use feo_error::{error::CompilerError, handler::Handler};
+use feo_error::{diagnostic::Diagnostic, handler::SharedHandler};
+use feo_types::source_map::SourceMap;
 
 use crate::{lexer::Lexer, parser::Parser};
 
 /// Test helper for creating a generic instance of `Parser` with the given source code
-pub fn get_parser(source_code: &str, print_stream: bool) -> Result<Parser, Vec<CompilerError>> {
-    let handler = Handler::default();
+pub fn get_parser(
+    source_code: &str,
+    print_stream: bool,
+) -> Result<Parser<'static>, Vec<Diagnostic>> {
+    get_parser_with_source_map(&mut SourceMap::new(), source_code, print_stream)
+}
+
+/// Test helper like `get_parser()`, which adds the source code to `source_map`,
+/// so that spans and diagnostics can be resolved against it afterwards
+pub fn get_parser_with_source_map(
+    source_map: &mut SourceMap,
+    source_code: &str,
+    print_stream: bool,
+) -> Result<Parser<'static>, Vec<Diagnostic>> {
+    let handler = SharedHandler::default();
+
+    let src_id = source_map.add_file("test", source_code);
+
+    let mut lexer = Lexer::new(source_map, src_id, handler.clone());
 
-    let mut lexer = Lexer::new(&source_code, handler.clone());
+    let token_stream = lexer.lex();
 
-    let token_stream = if let Ok(l) = lexer.lex() {
-        l
-    } else {
+    if !lexer.errors().is_empty() {
         return Err(lexer.errors());
-    };
+    }
 
     match print_stream {
         true => println!("tokens: {:#?}", token_stream),
@@ -40,8 +56,11 @@ pub fn log_msg(msg_type: LogMsgType, object_name: &str, parser: &mut Parser) ->
         LogMsgType::Expect => "expected",
     };
 
-    println!(
-        "{msg_str} {object_name}...\ncurrent_token: {:#?}",
-        parser.current_token()
-    );
-}
\ No newline at end of file
+    // only print in tests, so that the parser does not write to stdout when used as a library
+    if cfg!(test) {
+        println!(
+            "{msg_str} {object_name}...\ncurrent_token: {:#?}",
+            parser.current_token()
+        );
+    }
+}
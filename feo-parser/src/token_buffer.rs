@@ -0,0 +1,192 @@
+use feo_ast::token::Token;
+
+/// Maximum number of tokens past the current one that the parser can look ahead
+pub const MAX_LOOKAHEAD: usize = 4;
+
+/// Lookahead buffer over a stream of tokens (e.g., a `Lexer`), which are pulled from the
+/// stream on demand rather than being collected up front.
+/// Only the tokens from the previous token (or the held token, if that is earlier) up to the
+/// furthest one peeked at are kept, and indexes are positions in the whole stream.
+pub struct TokenBuffer<'a> {
+    stream: Box<dyn Iterator<Item = Token> + 'a>,
+    tokens: Vec<Token>,
+    offset: usize,       // stream index of `tokens[0]`
+    hold: Option<usize>, // stream index of the first token that must not be released
+}
+
+impl<'a> TokenBuffer<'a> {
+    pub fn new<I>(stream: I) -> Self
+    where
+        I: IntoIterator<Item = Token>,
+        I::IntoIter: 'a,
+    {
+        Self {
+            stream: Box::new(stream.into_iter()),
+            tokens: Vec::new(),
+            offset: 0,
+            hold: None,
+        }
+    }
+
+    /// Return the token at `index` in the stream, or `None` if the stream ends before it.
+    pub fn get(&mut self, index: usize) -> Option<Token> {
+        self.tokens_from(index).first().cloned()
+    }
+
+    /// Return the buffered tokens, starting at `index` in the stream
+    /// (after pulling any that are not buffered yet, up to `index`).
+    /// Panic if the token at `index` has already been released.
+    pub fn tokens_from(&mut self, index: usize) -> &[Token] {
+        assert!(
+            index >= self.offset,
+            "BufferError: token at index {index} has already been released"
+        );
+
+        while self.offset + self.tokens.len() <= index {
+            match self.stream.next() {
+                Some(t) => self.tokens.push(t),
+                None => break,
+            }
+        }
+
+        self.tokens.get(index - self.offset..).unwrap_or(&[])
+    }
+
+    /// Drop the buffered tokens that come before `index` in the stream
+    /// (or before the held token, if that is earlier).
+    pub fn release(&mut self, index: usize) {
+        let index = self.hold.map_or(index, |h| h.min(index));
+
+        if index > self.offset {
+            let num_tokens = (index - self.offset).min(self.tokens.len());
+
+            self.tokens.drain(..num_tokens);
+            self.offset += num_tokens;
+        }
+    }
+
+    /// Keep the tokens from `index` in the stream on buffered (e.g., so that the parser can
+    /// go back to it), until the hold is replaced or lifted with `None`.
+    /// Return the previous hold.
+    pub fn hold(&mut self, index: Option<usize>) -> Option<usize> {
+        std::mem::replace(&mut self.hold, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use feo_ast::expression::MethodCallExpr;
+    use feo_error::handler::{Handler, SharedHandler};
+    use feo_types::{source_map::SourceMap, span::Spanned};
+
+    use crate::{lexer::Lexer, parse::ParseExpr, parser::Parser};
+
+    use super::*;
+
+    #[test]
+    fn buffer_tokens_on_demand() {
+        let source_code = r#"let x = foo(1, 2);"#;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("test", source_code);
+
+        let mut buffer = TokenBuffer::new(Lexer::new(&source_map, src_id, Handler::default()));
+
+        assert!(buffer.tokens.is_empty());
+
+        // peeking ahead only pulls the tokens up to the one peeked at
+        assert!(matches!(buffer.get(2), Some(Token::Punc(_))));
+        assert_eq!(buffer.tokens.len(), 3);
+
+        buffer.release(2);
+        assert_eq!(buffer.tokens.len(), 1);
+        assert_eq!(buffer.tokens_from(2).len(), 1);
+
+        assert!(matches!(buffer.get(4), Some(Token::Delim(_))));
+        assert!(matches!(buffer.get(9), Some(Token::Punc(_))));
+        assert!(buffer.get(10).is_none());
+
+        // releasing past the end of the buffer drops everything that was buffered
+        buffer.release(20);
+        assert!(buffer.tokens.is_empty());
+        assert!(buffer.get(10).is_none());
+    }
+
+    #[test]
+    fn hold_tokens() {
+        let source_code = r#"t.0.1"#;
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("test", source_code);
+
+        let mut buffer = TokenBuffer::new(Lexer::new(&source_map, src_id, Handler::default()));
+
+        assert_eq!(buffer.hold(Some(1)), None);
+
+        // held tokens are kept, so that they can be returned to
+        buffer.get(4);
+        buffer.release(3);
+        assert!(matches!(buffer.get(1), Some(Token::Punc(_))));
+
+        assert_eq!(buffer.hold(None), Some(1));
+
+        buffer.release(3);
+        assert_eq!(buffer.tokens.len(), 2);
+    }
+
+    #[test]
+    fn parse_from_lexer() {
+        let source_code = r#"x.1.0.method(y, 2)"#;
+
+        let handler = SharedHandler::default();
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("test", source_code);
+
+        // the parser pulls tokens from the lexer as it goes, rather than from a `TokenStream`
+        let mut parser = Parser::new(
+            Lexer::new(&source_map, src_id, handler.clone()),
+            handler.clone(),
+        );
+
+        let method_call_expr = MethodCallExpr::parse(&mut parser)
+            .expect("unable to parse method call expression")
+            .expect("method call expression not found");
+
+        assert_eq!(method_call_expr.span().as_str(&source_map), source_code);
+        assert!(parser.errors().is_empty());
+    }
+
+    #[test]
+    fn lex_errors_reach_handler() {
+        let source_code = r#"x.method('ab', 2)"#;
+
+        let handler = SharedHandler::default();
+
+        let mut source_map = SourceMap::new();
+
+        let src_id = source_map.add_file("test", source_code);
+
+        let mut parser = Parser::new(
+            Lexer::new(&source_map, src_id, handler.clone()),
+            handler.clone(),
+        );
+
+        MethodCallExpr::parse(&mut parser).expect_err("invalid token not reported");
+
+        // the lexer and the parser share the same `Handler`, so errors that are found
+        // while the parser pulls tokens are not lost
+        let codes = handler
+            .diagnostics()
+            .0
+            .iter()
+            .filter_map(|e| e.code.clone())
+            .collect::<Vec<String>>();
+
+        // the parser reports the `Token::Error` that stands in for the invalid char literal
+        assert_eq!(codes, ["E0214", "E0102"]);
+    }
+}
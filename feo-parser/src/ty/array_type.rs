@@ -1,5 +1,5 @@
 use feo_ast::{token::Token, ty::ArrayType, Type};
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     literal::UIntType,
@@ -10,7 +10,7 @@ use feo_types::{
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for ArrayType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -91,7 +91,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_array_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_array_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"[u8; 32]"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -1,12 +1,12 @@
 use std::str::FromStr;
 
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{span::Spanned, type_annotation::TypeAnnotation, BuiltInType, Identifier};
 
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for BuiltInType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -32,7 +32,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_built_in_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_built_in_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"u64"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -5,7 +5,7 @@ use feo_ast::{
     ty::{ClosureType, FunctionType},
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     keyword::KeywordKind,
@@ -20,7 +20,7 @@ use crate::{
 };
 
 impl ParseType for FunctionType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -98,7 +98,7 @@ impl ParseType for FunctionType {
 }
 
 impl ParseType for ClosureType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -137,7 +137,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_function_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_function_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         func (foo: u64, bar: bool) -> char
         "#;
@@ -151,7 +151,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_closure_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_closure_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"
         |foo: u64, bar: bool| -> char
         "#;
@@ -1,5 +1,5 @@
 use feo_ast::{path::PathType, ty::ImplTraitType};
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{keyword::KeywordKind, Keyword};
 
 use crate::{
@@ -8,7 +8,7 @@ use crate::{
 };
 
 impl ParseType for ImplTraitType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -43,7 +43,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_impl_trait_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_impl_trait_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"impl Foo"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
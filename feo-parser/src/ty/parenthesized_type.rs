@@ -1,5 +1,5 @@
 use feo_ast::{token::Token, ty::ParenthesizedType, Type};
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     Delimiter,
@@ -8,7 +8,7 @@ use feo_types::{
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for ParenthesizedType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -66,7 +66,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_parenthesized_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_parenthesized_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(u64)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -1,11 +1,11 @@
 use feo_ast::{token::Token, ty::ReferenceType, Type};
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{keyword::KeywordKind, punctuation::PuncKind, Keyword, Punctuation};
 
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for ReferenceType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -58,7 +58,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_reference_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_reference_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"&mut bool"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -1,11 +1,11 @@
 use feo_ast::ty::SelfType;
-use feo_error::error::CompilerError;
+use feo_error::diagnostic::Diagnostic;
 use feo_types::{keyword::KeywordKind, Keyword};
 
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for SelfType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -32,7 +32,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_self_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_self_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"Self"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -3,7 +3,7 @@ use feo_ast::{
     ty::{TupleType, UnitType},
     Type,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
     punctuation::PuncKind,
@@ -13,7 +13,7 @@ use feo_types::{
 use crate::{parse::ParseType, parser::Parser};
 
 impl ParseType for TupleType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -101,7 +101,7 @@ impl ParseType for TupleType {
 }
 
 impl ParseType for UnitType {
-    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<CompilerError>>
+    fn parse(parser: &mut Parser) -> Result<Option<Self>, Vec<Diagnostic>>
     where
         Self: Sized,
     {
@@ -149,7 +149,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_tuple_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_tuple_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"(u64, char, bool)"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
@@ -160,7 +160,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_unit_type() -> Result<(), Vec<CompilerError>> {
+    fn parse_unit_type() -> Result<(), Vec<Diagnostic>> {
         let source_code = r#"()"#;
 
         let mut parser = test_utils::get_parser(source_code, false)?;
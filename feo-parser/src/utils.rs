@@ -1,14 +1,15 @@
 use feo_ast::{
-    expression::{TermCollection, Value, ValueCollection},
+    expression::{TermCollection, TupleIndexExpr, Value, ValueCollection},
     item::{PathCollection, VisibilityKind},
     statement::Statement,
     token::Token,
 };
-use feo_error::{error::CompilerError, parser_error::ParserErrorKind};
+use feo_error::{diagnostic::Diagnostic, parser_error::ParserErrorKind};
 use feo_types::{
     delimiter::{DelimKind, DelimOrientation},
+    literal::UIntType,
     punctuation::PuncKind,
-    Delimiter, Punctuation,
+    Delimiter, Literal, Punctuation, U64Primitive,
 };
 
 use crate::{
@@ -17,12 +18,54 @@ use crate::{
     test_utils::{self, LogMsgType},
 };
 
+/// Helper function that wraps an operand in a `TupleIndexExpr` for each `.` and integer index
+/// that follows it (e.g., `t.0.1` is `(t.0).1`), leaving the parser on the last index
+/// Returns the operand unchanged if it is not followed by a tuple index
+pub fn get_tuple_index_chain(
+    parser: &mut Parser,
+    operand: Value,
+) -> Result<Value, Vec<Diagnostic>> {
+    let mut value = operand;
+
+    while let (
+        Some(Punctuation {
+            punc_kind: PuncKind::FullStop,
+            ..
+        }),
+        Some(index),
+    ) = (
+        parser.peek_next::<Punctuation>(),
+        parser.peek_ahead::<Literal<UIntType>>(2),
+    ) {
+        parser.next_token();
+        parser.next_token();
+
+        let index = match U64Primitive::try_from(index) {
+            Ok(i) => i,
+            Err(_) => {
+                parser.log_error(ParserErrorKind::InvalidToken {
+                    token: parser.current_token().unwrap_or(Token::EOF).to_string(),
+                });
+
+                return Err(parser.errors());
+            }
+        };
+
+        value = Value::TupleIndexExpr(TupleIndexExpr {
+            operand: Box::new(value),
+            index,
+        });
+    }
+
+    Ok(value)
+}
+
 /// Helper function that collects attributes during parsing
 /// `T` should only be an `InnerAttr` or `OuterAttr`
 // TODO: restrict `T` to be either an `InnerAttr` or `OuterAttr` (using traits)
 pub fn get_attributes<T: ParseTerm>(
     parser: &mut Parser,
-) -> Result<Option<Vec<T>>, Vec<CompilerError>> {
+) -> Result<Option<Vec<T>>, Vec<Diagnostic>> {
     // test_utils::log_msg(LogMsgType::Enter, "`get_attributes()`", parser);
 
     let mut attributes: Vec<T> = Vec::new();
@@ -32,9 +75,6 @@ pub fn get_attributes<T: ParseTerm>(
         parser.next_token();
     }
 
-    // TODO: remove after initial debugging
-    println!("number of attributes: {}", attributes.len());
-
     // test_utils::log_msg(LogMsgType::Exit, "`get_attributes()`", parser);
 
     if attributes.is_empty() {
@@ -46,7 +86,7 @@ pub fn get_attributes<T: ParseTerm>(
 
 /// Helper function that collects `Item` during parsing.
 /// e.g., `ConstVarDef`, `FuncDef`, `StructDef`, etc.
-pub fn get_items<T: ParseItem>(parser: &mut Parser) -> Result<Option<Vec<T>>, Vec<CompilerError>> {
+pub fn get_items<T: ParseItem>(parser: &mut Parser) -> Result<Option<Vec<T>>, Vec<Diagnostic>> {
     test_utils::log_msg(LogMsgType::Enter, "`get_items()`", parser);
 
     let mut items: Vec<T> = Vec::new();
@@ -56,9 +96,6 @@ pub fn get_items<T: ParseItem>(parser: &mut Parser) -> Result<Option<Vec<T>>, Ve
         items.push(i);
     }
 
-    // TODO: remove after initial debugging
-    println!("number of items: {}", items.len());
-
     test_utils::log_msg(LogMsgType::Exit, "`get_items()`", parser);
 
     if items.is_empty() {
@@ -72,7 +109,7 @@ pub fn get_items<T: ParseItem>(parser: &mut Parser) -> Result<Option<Vec<T>>, Ve
 /// `T` should resolve to some `PathIdenSegmentKind` or `SimplePathSegmentKind`
 pub fn get_path_collection<T: ParseTerm>(
     parser: &mut Parser,
-) -> Result<Option<PathCollection<T>>, Vec<CompilerError>> {
+) -> Result<Option<PathCollection<T>>, Vec<Diagnostic>> {
     test_utils::log_msg(LogMsgType::Enter, "`get_path_collection()`", parser);
 
     if let Some(root_path) = T::parse(parser)? {
@@ -142,7 +179,7 @@ pub fn get_path_collection<T: ParseTerm>(
 
 /// Helper function that collects `Statement` during parsing.
 /// Statements include all `Item`, as well as `ExprStatement` and `LetStatement`
-pub fn get_statements(parser: &mut Parser) -> Result<Option<Vec<Statement>>, Vec<CompilerError>> {
+pub fn get_statements(parser: &mut Parser) -> Result<Option<Vec<Statement>>, Vec<Diagnostic>> {
     test_utils::log_msg(LogMsgType::Enter, "`get_statements()`", parser);
 
     let mut statements: Vec<Statement> = Vec::new();
@@ -159,9 +196,6 @@ pub fn get_statements(parser: &mut Parser) -> Result<Option<Vec<Statement>>, Vec
         }
     }
 
-    // TODO: remove after initial debugging
-    println!("number of statements: {}", statements.len());
-
     test_utils::log_msg(LogMsgType::Exit, "`get_statements()`", parser);
 
     if statements.is_empty() {
@@ -174,7 +208,7 @@ pub fn get_statements(parser: &mut Parser) -> Result<Option<Vec<Statement>>, Vec
 /// Helper function that collects terms during parsing (i.e., elements of `Item` and `Expression`)
 pub fn get_term_collection<T: ParseTerm>(
     parser: &mut Parser,
-) -> Result<Option<TermCollection<T>>, Vec<CompilerError>> {
+) -> Result<Option<TermCollection<T>>, Vec<Diagnostic>> {
     // test_utils::log_msg(LogMsgType::Enter, "`get_term_collection()`", parser);
 
     let mut terms: Vec<T> = Vec::new();
@@ -197,8 +231,7 @@ pub fn get_term_collection<T: ParseTerm>(
             }
         }
 
-        // TODO: remove after initial debugging
-        println!("number of terms: {}", terms.len() + 1);
+        check_missing_comma(parser)?;
 
         let subsequent_terms_opt = if terms.is_empty() { None } else { Some(terms) };
 
@@ -215,7 +248,7 @@ pub fn get_term_collection<T: ParseTerm>(
 /// Helper function that collects `Value` (`Expression`) during parsing
 pub fn get_value_collection(
     parser: &mut Parser,
-) -> Result<Option<ValueCollection>, Vec<CompilerError>> {
+) -> Result<Option<ValueCollection>, Vec<Diagnostic>> {
     let mut values: Vec<Value> = Vec::new();
 
     if let Some(first_value) = Value::parse(parser)? {
@@ -236,8 +269,7 @@ pub fn get_value_collection(
             }
         }
 
-        // TODO: remove after initial debugging
-        println!("number of values: {}", values.len() + 1);
+        check_missing_comma(parser)?;
 
         test_utils::log_msg(LogMsgType::Exit, "`get_value_collection()`", parser);
 
@@ -257,7 +289,7 @@ pub fn get_value_collection(
 }
 
 /// Helper function that returns `VisibilityKind` during parsing
-pub fn get_visibility(parser: &mut Parser) -> Result<Option<VisibilityKind>, Vec<CompilerError>> {
+pub fn get_visibility(parser: &mut Parser) -> Result<Option<VisibilityKind>, Vec<Diagnostic>> {
     // test_utils::log_msg(LogMsgType::Enter, "`get_visibility()`", parser);
 
     if let Some(v) = VisibilityKind::parse(parser)? {
@@ -271,8 +303,36 @@ pub fn get_visibility(parser: &mut Parser) -> Result<Option<VisibilityKind>, Vec
     }
 }
 
+/// Helper function that reports a missing comma between the elements of a collection,
+/// i.e., a literal or identifier straight after an element (e.g., `foo(a b)`),
+/// as neither can continue the element or end the collection
+pub fn check_missing_comma(parser: &mut Parser) -> Result<(), Vec<Diagnostic>> {
+    match parser.current_token() {
+        Some(
+            t @ (Token::CharLit(_)
+            | Token::StringLit(_)
+            | Token::ByteLit(_)
+            | Token::ByteStringLit(_)
+            | Token::BoolLit(_)
+            | Token::IntLit(_)
+            | Token::UIntLit(_)
+            | Token::U256Lit(_)
+            | Token::FloatLit(_)
+            | Token::Identifier(_)),
+        ) => {
+            parser.log_error(ParserErrorKind::UnexpectedToken {
+                expected: "`,`".to_string(),
+                found: t.to_string(),
+            });
+
+            Err(parser.errors())
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Helper function that skips trailing commas
-pub fn skip_trailing_comma(parser: &mut Parser) -> Result<(), Vec<CompilerError>> {
+pub fn skip_trailing_comma(parser: &mut Parser) -> Result<(), Vec<Diagnostic>> {
     if let Some(Punctuation {
         punc_kind: PuncKind::Comma,
         ..
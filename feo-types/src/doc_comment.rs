@@ -2,8 +2,10 @@ use crate::span::{Span, Spanned};
 
 #[derive(Debug, Clone)]
 pub enum DocCommentKind {
-    InnerDocComment, // slash-slash-bang
-    OuterDocComment, // slash-slash-slash
+    InnerDocComment,      // slash-slash-bang
+    OuterDocComment,      // slash-slash-slash
+    InnerBlockDocComment, // slash-asterisk-bang | close: asterisk-slash
+    OuterBlockDocComment, // slash-asterisk-asterisk | close: asterisk-slash
 }
 
 #[derive(Debug, Clone)]
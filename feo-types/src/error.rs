@@ -14,6 +14,22 @@ pub enum TypeErrorKind {
     UnknownError,
 }
 
+impl TypeErrorKind {
+    /// Return the stable code of the error (e.g., to look up its explanation).
+    /// Codes are never reused or renumbered, even if the error kind is removed.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TypeErrorKind::UnrecognizedCommentOpener => "E0301",
+            TypeErrorKind::UnrecognizedDelimiter => "E0302",
+            TypeErrorKind::UnrecognizedKeyword => "E0303",
+            TypeErrorKind::UnexpectedPunctuation => "E0304",
+            TypeErrorKind::MismatchedTypes => "E0305",
+            TypeErrorKind::ValueNotFound => "E0306",
+            TypeErrorKind::UnknownError => "E0300",
+        }
+    }
+}
+
 impl fmt::Display for TypeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
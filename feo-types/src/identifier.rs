@@ -1,26 +1,75 @@
-use crate::span::{Span, Spanned};
+use std::str::FromStr;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
+
+use crate::{
+    keyword::{Keyword, KeywordKind},
+    span::{Span, Spanned},
+};
 
 #[derive(Debug, Clone)]
 pub struct Identifier {
     pub name: String,
+    pub is_raw: bool, // `r#name`
     span: Span,
 }
 
 impl Identifier {
+    /// Create an identifier, normalising its name to Unicode NFC (so that, e.g., `é` written
+    /// as `e` + combining acute accent and `é` written as a single code point are the same name).
     pub fn new(name: String, span: Span) -> Self {
-        Self { name, span }
+        Self {
+            name: name.nfc().collect::<String>(),
+            is_raw: false,
+            span,
+        }
+    }
+
+    /// Create a raw identifier (written as `r#name`), which may have the same name as a keyword.
+    /// `name` excludes the `r#` prefix.
+    pub fn new_raw(name: String, span: Span) -> Self {
+        Self {
+            is_raw: true,
+            ..Identifier::new(name, span)
+        }
+    }
+
+    /// Return the contextual keyword that the identifier is spelled as (if any),
+    /// for use where the grammar expects that keyword (e.g., `storage` in `#[storage]`).
+    /// A raw identifier is never a keyword.
+    pub fn as_contextual_keyword(&self) -> Option<Keyword> {
+        if self.is_raw {
+            return None;
+        }
+
+        KeywordKind::from_str(&self.name)
+            .ok()
+            .filter(KeywordKind::is_contextual)
+            .map(|k| Keyword::new(k, self.span))
     }
 }
 
-pub fn is_keyword(iden: &str) -> bool {
-    [
-        "abi", "abstract", "as", "break", "const", "continue", "contract", "else", "enum",
-        "export", "extern", "for", "func", "if", "impl", "import", "in", "let", "library", "loop",
-        "match", "module", "mut", "package", "payable", "pub", "ref", "return", "script", "self",
-        "Self", "static", "storage", "struct", "super", "test", "topic", "trait", "type", "unsafe",
-        "while",
-    ]
-    .contains(&iden)
+/// Check whether `iden` is a strict keyword, which is reserved and cannot be used as an
+/// (unprefixed) identifier.
+pub fn is_strict_keyword(iden: &str) -> bool {
+    KeywordKind::from_str(iden).is_ok_and(|k| !k.is_contextual())
+}
+
+/// Check whether `iden` is a contextual keyword, which is only a keyword where the grammar
+/// expects it and is lexed as an identifier.
+pub fn is_contextual_keyword(iden: &str) -> bool {
+    KeywordKind::from_str(iden).is_ok_and(|k| k.is_contextual())
+}
+
+/// Check whether `c` can start an identifier (`_` or a Unicode `XID_Start` char).
+pub fn is_identifier_start(c: char) -> bool {
+    c == '_' || c.is_xid_start()
+}
+
+/// Check whether `c` can continue an identifier (a Unicode `XID_Continue` char).
+pub fn is_identifier_continue(c: char) -> bool {
+    c.is_xid_continue()
 }
 
 impl Spanned for Identifier {
@@ -51,6 +51,51 @@ pub enum KeywordKind {
 }
 
 impl KeywordKind {
+    /// All the keywords (e.g., to suggest one for a misspelled word)
+    pub const ALL: &'static [KeywordKind] = &[
+        KeywordKind::KwAbi,
+        KeywordKind::KwAbstract,
+        KeywordKind::KwAs,
+        KeywordKind::KwBreak,
+        KeywordKind::KwConst,
+        KeywordKind::KwContinue,
+        KeywordKind::KwContract,
+        KeywordKind::KwElse,
+        KeywordKind::KwEnum,
+        KeywordKind::KwExport,
+        KeywordKind::KwExtern,
+        KeywordKind::KwFor,
+        KeywordKind::KwFunc,
+        KeywordKind::KwIf,
+        KeywordKind::KwImpl,
+        KeywordKind::KwImport,
+        KeywordKind::KwIn,
+        KeywordKind::KwLet,
+        KeywordKind::KwLibrary,
+        KeywordKind::KwLoop,
+        KeywordKind::KwMatch,
+        KeywordKind::KwModule,
+        KeywordKind::KwMut,
+        KeywordKind::KwPackage,
+        KeywordKind::KwPayable,
+        KeywordKind::KwPub,
+        KeywordKind::KwRef,
+        KeywordKind::KwReturn,
+        KeywordKind::KwScript,
+        KeywordKind::KwSelf,
+        KeywordKind::KwSelfType,
+        KeywordKind::KwStatic,
+        KeywordKind::KwStorage,
+        KeywordKind::KwStruct,
+        KeywordKind::KwSuper,
+        KeywordKind::KwTest,
+        KeywordKind::KwTopic,
+        KeywordKind::KwTrait,
+        KeywordKind::KwType,
+        KeywordKind::KwUnsafe,
+        KeywordKind::KwWhile,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             KeywordKind::KwAbi => "abi",
@@ -96,6 +141,26 @@ impl KeywordKind {
             KeywordKind::KwWhile => "while",
         }
     }
+
+    /// Check whether the keyword is contextual, i.e., only has a special meaning in certain
+    /// places (such as attributes or at the start of an `abi` item) and is otherwise an
+    /// ordinary identifier. All the other keywords are strict (reserved).
+    pub fn is_contextual(&self) -> bool {
+        matches!(
+            self,
+            KeywordKind::KwAbi
+                | KeywordKind::KwAbstract
+                | KeywordKind::KwContract
+                | KeywordKind::KwExport
+                | KeywordKind::KwExtern
+                | KeywordKind::KwLibrary
+                | KeywordKind::KwPayable
+                | KeywordKind::KwScript
+                | KeywordKind::KwStorage
+                | KeywordKind::KwTest
+                | KeywordKind::KwTopic
+        )
+    }
 }
 
 impl FromStr for KeywordKind {
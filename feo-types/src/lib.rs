@@ -30,6 +30,8 @@ pub use crate::primitive::*;
 pub mod punctuation;
 pub use crate::punctuation::Punctuation;
 
+pub mod source_map;
+
 pub mod span;
 
 pub mod type_annotation;
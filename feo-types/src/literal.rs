@@ -11,6 +11,10 @@ impl LiteralType for char {}
 
 impl LiteralType for String {}
 
+impl LiteralType for u8 {}
+
+impl LiteralType for Vec<u8> {}
+
 impl LiteralType for bool {}
 
 impl LiteralType for IntType {}
@@ -98,7 +102,7 @@ where
     T: LiteralType,
 {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -115,6 +119,8 @@ where
 pub enum LiteralKind {
     Char(Literal<char>),
     String(Literal<String>),
+    Byte(Literal<u8>),
+    ByteString(Literal<Vec<u8>>),
     Bool(Literal<bool>),
     Int(Literal<IntType>),
     UInt(Literal<UIntType>),
@@ -131,6 +137,14 @@ impl LiteralKind {
         LiteralKind::String(Literal::new(String::default(), Span::default()))
     }
 
+    pub fn default_byte() -> LiteralKind {
+        LiteralKind::Byte(Literal::new(u8::default(), Span::default()))
+    }
+
+    pub fn default_byte_string() -> LiteralKind {
+        LiteralKind::ByteString(Literal::new(Vec::<u8>::default(), Span::default()))
+    }
+
     pub fn default_bool() -> LiteralKind {
         LiteralKind::Bool(Literal::new(bool::default(), Span::default()))
     }
@@ -160,6 +174,8 @@ impl Spanned for LiteralKind {
         match self {
             LiteralKind::Char(c) => c.span(),
             LiteralKind::String(s) => s.span(),
+            LiteralKind::Byte(b) => b.span(),
+            LiteralKind::ByteString(bs) => bs.span(),
             LiteralKind::Bool(b) => b.span(),
             LiteralKind::Int(i) => i.span(),
             LiteralKind::UInt(ui) => ui.span(),
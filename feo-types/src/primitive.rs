@@ -36,7 +36,7 @@ impl CharPrimitive {
 
 impl Spanned for CharPrimitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -59,7 +59,7 @@ impl StrPrimitive {
 
 impl Spanned for StrPrimitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -82,7 +82,7 @@ impl BoolPrimitive {
 
 impl Spanned for BoolPrimitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -105,7 +105,7 @@ impl I32Primitive {
 
 impl Spanned for I32Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -128,7 +128,7 @@ impl I64Primitive {
 
 impl Spanned for I64Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -151,7 +151,7 @@ impl U8Primitive {
 
 impl Spanned for U8Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -174,7 +174,7 @@ impl U16Primitive {
 
 impl Spanned for U16Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -197,7 +197,7 @@ impl U32Primitive {
 
 impl Spanned for U32Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -220,7 +220,7 @@ impl U64Primitive {
 
 impl Spanned for U64Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -263,7 +263,7 @@ impl U256Primitive {
 
 impl Spanned for U256Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -286,7 +286,7 @@ impl F32Primitive {
 
 impl Spanned for F32Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
 
@@ -309,6 +309,6 @@ impl F64Primitive {
 
 impl Spanned for F64Primitive {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
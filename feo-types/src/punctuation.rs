@@ -44,6 +44,9 @@ pub enum PuncKind {
     PlusEquals,
     MinusEquals,
     ForwardSlashEquals,
+    AmpersandEquals,
+    PipeEquals,
+    CaretEquals,
     DblLessThan,
     LessThanEquals,
     DblEquals,
@@ -54,6 +57,8 @@ pub enum PuncKind {
     DblPipe,
 
     ColonColonAsterisk,
+    DblLessThanEquals,
+    DblGreaterThanEquals,
 }
 
 impl PuncKind {
@@ -93,6 +98,9 @@ impl PuncKind {
             PuncKind::PlusEquals => "+=",
             PuncKind::MinusEquals => "-=",
             PuncKind::ForwardSlashEquals => "/=",
+            PuncKind::AmpersandEquals => "&=",
+            PuncKind::PipeEquals => "|=",
+            PuncKind::CaretEquals => "^=",
             PuncKind::DblLessThan => "<<",
             PuncKind::LessThanEquals => "<=",
             PuncKind::DblEquals => "==",
@@ -102,6 +110,8 @@ impl PuncKind {
             PuncKind::FatArrow => "=>",
             PuncKind::DblPipe => "||",
             PuncKind::ColonColonAsterisk => "::*",
+            PuncKind::DblLessThanEquals => "<<=",
+            PuncKind::DblGreaterThanEquals => ">>=",
         }
     }
 }
@@ -145,6 +155,9 @@ impl FromStr for PuncKind {
             "+=" => Ok(PuncKind::PlusEquals),
             "-=" => Ok(PuncKind::MinusEquals),
             "/=" => Ok(PuncKind::ForwardSlashEquals),
+            "&=" => Ok(PuncKind::AmpersandEquals),
+            "|=" => Ok(PuncKind::PipeEquals),
+            "^=" => Ok(PuncKind::CaretEquals),
             "<<" => Ok(PuncKind::DblLessThan),
             "<=" => Ok(PuncKind::LessThanEquals),
             "==" => Ok(PuncKind::DblEquals),
@@ -154,6 +167,8 @@ impl FromStr for PuncKind {
             "=>" => Ok(PuncKind::FatArrow),
             "||" => Ok(PuncKind::DblPipe),
             "::*" => Ok(PuncKind::ColonColonAsterisk),
+            "<<=" => Ok(PuncKind::DblLessThanEquals),
+            ">>=" => Ok(PuncKind::DblGreaterThanEquals),
             _ => Err(TypeErrorKind::UnexpectedPunctuation),
         }?;
 
@@ -179,6 +194,19 @@ impl Spanned for Punctuation {
     }
 }
 
+/// Length (in bytes) of the longest punctuation (e.g., `<<=`).
+pub const MAX_PUNC_LEN: usize = 3;
+
+/// Return the longest punctuation at the start of `s` and its length in bytes (maximal munch),
+/// e.g., `"<<=1"` -> `(PuncKind::DblLessThanEquals, 3)` and `">-b"` -> `(PuncKind::GreaterThan, 1)`.
+pub fn longest_match(s: &str) -> Option<(PuncKind, usize)> {
+    (1..=MAX_PUNC_LEN).rev().find_map(|len| {
+        s.get(..len)
+            .and_then(|p| PuncKind::from_str(p).ok())
+            .map(|punc_kind| (punc_kind, len))
+    })
+}
+
 pub fn is_quote(c: char) -> bool {
     ['\'', '"'].contains(&c)
 }
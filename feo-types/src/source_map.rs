@@ -0,0 +1,333 @@
+/// Handle to a `SourceFile` registered with a `SourceMap`: the index of the file and the
+/// version of its contents, which is bumped every time the file is edited.
+/// The dummy ID (index 0) is reserved for spans that do not point into any file
+/// (e.g., `Span::default()`).
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceId {
+    index: u32,
+    version: u32,
+}
+
+impl SourceId {
+    /// Return the index of the file, which is the same for every version of it.
+    pub fn as_u32(&self) -> u32 {
+        self.index
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn is_dummy(&self) -> bool {
+        self.index == 0
+    }
+}
+
+/// Single source file, owned once by the `SourceMap` that it is registered with.
+/// Stores the byte offset of the start of every line, so that line / column lookups
+/// are a binary search instead of a rescan of the file.
+#[derive(Debug)]
+pub struct SourceFile {
+    id: SourceId,
+    name: String,
+    src: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(id: SourceId, name: &str, src: &str) -> Self {
+        let mut line_starts = vec![0];
+
+        line_starts.extend(
+            src.char_indices()
+                .filter(|(_, c)| *c == '\n')
+                .map(|(i, _)| i + 1),
+        );
+
+        Self {
+            id,
+            name: name.to_string(),
+            src: src.to_string(),
+            line_starts,
+        }
+    }
+
+    pub fn id(&self) -> SourceId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn src(&self) -> &str {
+        &self.src
+    }
+
+    pub fn len(&self) -> usize {
+        self.src.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.src.is_empty()
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Return the (zero-based) index of the line containing the byte offset `pos`.
+    fn line_index(&self, pos: usize) -> usize {
+        match self.line_starts.binary_search(&pos) {
+            Ok(idx) => idx,
+            Err(idx) => idx - 1,
+        }
+    }
+
+    /// Return the byte range of a (one-based) line, excluding the line break.
+    fn line_range(&self, line: usize) -> Option<(usize, usize)> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+
+        let end = match self.line_starts.get(line) {
+            Some(next_start) => next_start - 1,
+            None => self.src.len(),
+        };
+
+        // strip the `\r` of a `\r\n` line break
+        let end = if self.src[start..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        };
+
+        Some((start, end))
+    }
+
+    /// Return the one-based line and column (counted in chars) of the byte offset `pos`.
+    pub fn line_col(&self, pos: usize) -> (usize, usize) {
+        if pos > self.src.len() {
+            panic!("Position out of bounds");
+        }
+
+        let line_idx = self.line_index(pos);
+        let line_start = self.line_starts[line_idx];
+
+        (line_idx + 1, self.src[line_start..pos].chars().count() + 1)
+    }
+
+    /// Return the one-based line and column (counted in UTF-16 code units) of the byte
+    /// offset `pos`, as expected by most editor protocols.
+    pub fn line_col_utf16(&self, pos: usize) -> (usize, usize) {
+        if pos > self.src.len() {
+            panic!("Position out of bounds");
+        }
+
+        let line_idx = self.line_index(pos);
+        let line_start = self.line_starts[line_idx];
+
+        let col = self.src[line_start..pos]
+            .chars()
+            .map(char::len_utf16)
+            .sum::<usize>();
+
+        (line_idx + 1, col + 1)
+    }
+
+    /// Return the byte offset of a one-based line and column (counted in chars).
+    /// Returns `None` if the line does not exist or the column is past the end of the line.
+    pub fn offset_of(&self, line: usize, col: usize) -> Option<usize> {
+        let (start, end) = self.line_range(line)?;
+
+        let col_idx = col.checked_sub(1)?;
+
+        self.src[start..end]
+            .char_indices()
+            .map(|(i, _)| start + i)
+            .chain(std::iter::once(end))
+            .nth(col_idx)
+    }
+
+    /// Return the byte offset of a one-based line and column (counted in UTF-16 code units).
+    /// Returns `None` if the line does not exist, or the column is past the end of the line
+    /// or falls inside a surrogate pair.
+    pub fn offset_of_utf16(&self, line: usize, col: usize) -> Option<usize> {
+        let (start, end) = self.line_range(line)?;
+
+        let mut units = col.checked_sub(1)?;
+
+        for (i, c) in self.src[start..end].char_indices() {
+            if units == 0 {
+                return Some(start + i);
+            }
+
+            units = units.checked_sub(c.len_utf16())?;
+        }
+
+        if units == 0 {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    /// Return the text of a one-based line, excluding the line break.
+    pub fn line_text(&self, line: usize) -> Option<&str> {
+        let (start, end) = self.line_range(line)?;
+        Some(&self.src[start..end])
+    }
+}
+
+/// Registry that owns every source file and hands out `SourceId` for spans to refer to.
+/// It is created by the driver and passed to whatever needs the text of a span (e.g., the
+/// lexer and the renderer); its files are freed when it is dropped.
+#[derive(Default, Debug)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a source file and return its `SourceId`.
+    pub fn add_file(&mut self, name: &str, src: &str) -> SourceId {
+        // indexes start at 1; index 0 is the dummy ID
+        let id = SourceId {
+            index: self.files.len() as u32 + 1,
+            version: 0,
+        };
+
+        self.files.push(SourceFile::new(id, name, src));
+
+        id
+    }
+
+    /// Look up a registered file. Returns `None` for the dummy ID, an unknown ID or an ID of
+    /// a version of the file that has since been edited.
+    pub fn get(&self, id: SourceId) -> Option<&SourceFile> {
+        if id.is_dummy() {
+            return None;
+        }
+
+        self.files.get(id.index as usize - 1).filter(|f| f.id == id)
+    }
+
+    /// Return the full text of a registered file, or an empty string if it is not found.
+    pub fn source(&self, id: SourceId) -> &str {
+        self.get(id).map(|f| f.src()).unwrap_or("")
+    }
+
+    /// Apply `edit` to a registered file in place and return the `SourceId` of the new version.
+    /// The previous contents are dropped, so spans into the previous version no longer resolve.
+    /// Panics if `id` is not the current version of a registered file.
+    pub fn edit_file(&mut self, id: SourceId, edit: &TextEdit) -> SourceId {
+        let file = self
+            .get(id)
+            .expect("edited file is not the current version of a registered file");
+
+        let new_id = SourceId {
+            index: id.index,
+            version: id.version + 1,
+        };
+
+        let edited = SourceFile::new(new_id, &file.name, &edit.apply(&file.src));
+
+        self.files[id.index as usize - 1] = edited;
+
+        new_id
+    }
+}
+
+/// Replacement of the text between two byte offsets in a source file (e.g., a change in an
+/// editor), where an insertion has an empty range and a deletion has no new text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn new(start: usize, end: usize, new_text: &str) -> Self {
+        Self {
+            start,
+            end,
+            new_text: new_text.to_string(),
+        }
+    }
+
+    /// Return `src` with the edit applied.
+    /// Panics if the range of the edit is out of bounds or not on char boundaries.
+    pub fn apply(&self, src: &str) -> String {
+        let mut buf = src.to_string();
+        buf.replace_range(self.start..self.end, &self.new_text);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_col_lookup() {
+        let file = SourceFile::new(
+            SourceId::default(),
+            "test",
+            "let a = 1;\nlet b = 'é';\r\n\nfoo",
+        );
+
+        assert_eq!(file.line_count(), 4);
+
+        assert_eq!(file.line_col(0), (1, 1));
+        assert_eq!(file.line_col(4), (1, 5));
+        assert_eq!(file.line_col(11), (2, 1));
+        assert_eq!(file.line_col(22), (2, 11)); // closing `'`, after the two-byte `é`
+        assert_eq!(file.line_col(26), (3, 1));
+        assert_eq!(file.line_col(27), (4, 1));
+
+        assert_eq!(file.offset_of(2, 11), Some(22));
+        assert_eq!(file.offset_of(4, 4), Some(30));
+        assert_eq!(file.offset_of(4, 5), None);
+        assert_eq!(file.offset_of(5, 1), None);
+
+        assert_eq!(file.line_text(2), Some("let b = 'é';"));
+        assert_eq!(file.line_text(3), Some(""));
+    }
+
+    #[test]
+    fn edit_file() {
+        let mut map = SourceMap::new();
+
+        let id = map.add_file("edit", "let a = 1;");
+        let other_id = map.add_file("other", "let b = 2;");
+
+        let edit = TextEdit::new(4, 5, "abc");
+        let edited_id = map.edit_file(id, &edit);
+
+        // the file is replaced in place, and the old version no longer resolves
+        assert_eq!(edited_id.as_u32(), id.as_u32());
+        assert_eq!(edited_id.version(), 1);
+        assert!(map.get(id).is_none());
+        assert_eq!(map.source(edited_id), "let abc = 1;");
+        assert_eq!(map.get(edited_id).map(|f| f.name()), Some("edit"));
+        assert_eq!(map.get(edited_id).map(|f| f.line_col(12)), Some((1, 13)));
+        assert_eq!(map.source(other_id), "let b = 2;");
+
+        assert_eq!(TextEdit::new(0, 4, "").apply("let a"), "a");
+        assert_eq!(TextEdit::new(5, 5, ";").apply("let a"), "let a;");
+    }
+
+    #[test]
+    fn utf16_columns() {
+        let file = SourceFile::new(SourceId::default(), "test", "s = \"😀x\"");
+
+        // `😀` is 4 bytes in UTF-8, but 2 code units in UTF-16
+        assert_eq!(file.line_col(9), (1, 7));
+        assert_eq!(file.line_col_utf16(9), (1, 8));
+
+        assert_eq!(file.offset_of_utf16(1, 8), Some(9));
+        assert_eq!(file.offset_of_utf16(1, 7), None);
+    }
+}
@@ -1,59 +1,58 @@
 use std::cmp;
-use std::sync::Arc;
+
+use crate::source_map::{SourceFile, SourceId, SourceMap};
 
 pub trait Spanned {
     fn span(&self) -> Span;
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
 pub struct Position {
-    input: Arc<String>,
+    src_id: SourceId,
     pos: usize,
 }
 
 impl Position {
-    pub fn new(input: &str, pos: usize) -> Position {
-        input
-            .get(pos..)
-            .map(|_| Position {
-                input: Arc::new(input.to_string()),
-                pos,
-            })
-            .expect("Position out of bounds")
-    }
-
-    #[inline]
-    pub fn line_col(&self) -> (usize, usize) {
-        if self.pos > self.input.len() {
-            panic!("Position out of bounds");
-        }
+    pub fn new(src_id: SourceId, pos: usize) -> Position {
+        Position { src_id, pos }
+    }
 
-        let slice = &self.input[..self.pos];
-        let lines = slice.split('\n').collect::<Vec<_>>();
-        let line_count = lines.len();
-        let last_line_len = lines.last().unwrap_or(&"").chars().count() + 1;
+    pub fn source_id(&self) -> SourceId {
+        self.src_id
+    }
 
-        (line_count, last_line_len)
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Return the one-based line and column (counted in chars) of this position
+    /// in its file in `source_map`.
+    pub fn line_col(&self, source_map: &SourceMap) -> (usize, usize) {
+        match source_map.get(self.src_id) {
+            Some(file) => file.line_col(self.pos),
+            None => (1, 1),
+        }
+    }
+
+    /// Return the one-based line and column (counted in UTF-16 code units) of this position
+    /// in its file in `source_map`.
+    pub fn line_col_utf16(&self, source_map: &SourceMap) -> (usize, usize) {
+        match source_map.get(self.src_id) {
+            Some(file) => file.line_col_utf16(self.pos),
+            None => (1, 1),
+        }
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Span {
-    src: Arc<String>,
+    src_id: SourceId,
     start: usize,
     end: usize,
 }
 
 impl Span {
-    pub fn new(src: &str, start: usize, end: usize) -> Self {
-        if start > src.len() {
-            panic!("Start position out of bounds")
-        }
-
-        if end > src.len() {
-            panic!("End position out of bounds")
-        }
-
+    pub fn new(src_id: SourceId, start: usize, end: usize) -> Self {
         let mut start_ = start;
         let mut end_ = end;
 
@@ -64,14 +63,20 @@ impl Span {
         }
 
         Self {
-            src: Arc::new(src.to_string()),
+            src_id,
             start: start_,
             end: end_,
         }
     }
 
-    pub fn source(&self) -> Arc<String> {
-        Arc::clone(&self.src)
+    pub fn source_id(&self) -> SourceId {
+        self.src_id
+    }
+
+    /// Return the `SourceFile` in `source_map` that this span points into
+    /// (`None` for a default / dummy span).
+    pub fn source<'a>(&self, source_map: &'a SourceMap) -> Option<&'a SourceFile> {
+        source_map.get(self.src_id)
     }
 
     pub fn start(&self) -> usize {
@@ -82,23 +87,47 @@ impl Span {
         self.end
     }
 
-    pub fn len(&self) -> usize {
-        if self.end >= self.start {
-            self.end - self.start
-        } else {
-            self.start - self.end
+    pub fn start_pos(&self) -> Position {
+        Position {
+            src_id: self.src_id,
+            pos: self.start,
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.end.abs_diff(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn join(s1: Span, s2: Span) -> Span {
         Span {
-            src: s1.src,
+            src_id: s1.src_id,
             start: cmp::min(s1.start, s2.start),
             end: cmp::max(s1.end, s2.end),
         }
     }
 
-    pub fn as_str(&self) -> &str {
-        &self.src[self.start..self.end]
+    /// Return the text of the span in its file in `source_map`.
+    pub fn as_str<'a>(&self, source_map: &'a SourceMap) -> &'a str {
+        &source_map.source(self.src_id)[self.start..self.end]
+    }
+
+    /// Move the span by `delta` bytes and into the file `src_id`
+    /// (e.g., a new version of its file, with text inserted or removed before the span).
+    pub fn shift(&self, src_id: SourceId, delta: isize) -> Span {
+        let start = self
+            .start
+            .checked_add_signed(delta)
+            .expect("Start position out of bounds");
+
+        let end = self
+            .end
+            .checked_add_signed(delta)
+            .expect("End position out of bounds");
+
+        Span::new(src_id, start, end)
     }
 }
@@ -83,6 +83,6 @@ impl BuiltInType {
 
 impl Spanned for BuiltInType {
     fn span(&self) -> Span {
-        self.span.clone()
+        self.span
     }
 }
@@ -23,6 +23,7 @@ pub type KwImpl = Keyword;
 pub type KwImport = Keyword;
 pub type KwIn = Keyword;
 pub type KwLet = Keyword;
+pub type KwLibrary = Keyword;
 pub type KwLoop = Keyword;
 pub type KwMatch = Keyword;
 pub type KwModule = Keyword;
@@ -31,6 +32,7 @@ pub type KwPayable = Keyword;
 pub type KwPub = Keyword;
 pub type KwRef = Keyword;
 pub type KwReturn = Keyword;
+pub type KwScript = Keyword;
 pub type KwSelf = Keyword;
 pub type KwSelfType = Keyword;
 pub type KwStatic = Keyword;
@@ -46,11 +48,13 @@ pub type KwWhere = Keyword;
 pub type KwWhile = Keyword;
 
 pub type Ampersand = Punctuation;
+pub type AmpersandEquals = Punctuation;
 pub type Asterisk = Punctuation;
 pub type AsteriskEquals = Punctuation;
 pub type Bang = Punctuation;
 pub type BangEquals = Punctuation;
 pub type Caret = Punctuation;
+pub type CaretEquals = Punctuation;
 pub type Colon = Punctuation;
 pub type Comma = Punctuation;
 pub type DblAmpersand = Punctuation;
@@ -58,7 +62,9 @@ pub type DblColon = Punctuation;
 pub type DblDot = Punctuation;
 pub type DblEquals = Punctuation;
 pub type DblGreaterThan = Punctuation;
+pub type DblGreaterThanEquals = Punctuation;
 pub type DblLessThan = Punctuation;
+pub type DblLessThanEquals = Punctuation;
 pub type DblPipe = Punctuation;
 pub type FullStop = Punctuation;
 pub type DotDotEquals = Punctuation;
@@ -76,6 +82,7 @@ pub type MinusEquals = Punctuation;
 pub type Percent = Punctuation;
 pub type PercentEquals = Punctuation;
 pub type Pipe = Punctuation;
+pub type PipeEquals = Punctuation;
 pub type Plus = Punctuation;
 pub type PlusEquals = Punctuation;
 pub type QuestionMark = Punctuation;